@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dubins_path::*;
+
+/// get end poses spread evenly around the start, near and far
+fn end_poses() -> Vec<(Point<f64>, Angle<f64>)> {
+    let mut poses = Vec::new();
+    for x in -10..=10 {
+        for y in -10..=10 {
+            for angle in 0..8 {
+                let point = Point::new(x as f64 * 0.7, y as f64 * 0.7);
+                poses.push((point, Angle::radians(angle as f64 * 0.8)));
+            }
+        }
+    }
+    poses
+}
+
+/// compare getting only the length with constructing the shortest path
+fn shortest_length_benchmark(criterion: &mut Criterion) {
+    let poses = end_poses();
+    let mut group = criterion.benchmark_group("shortest length");
+
+    group.bench_function("get_shortest", |bencher| {
+        bencher.iter(|| {
+            for &(end_point, end_angle) in poses.iter() {
+                black_box(get_shortest(1.0, black_box(end_point), end_angle).get_length());
+            }
+        })
+    });
+    group.bench_function("shortest_length", |bencher| {
+        bencher.iter(|| {
+            for &(end_point, end_angle) in poses.iter() {
+                black_box(shortest_length(1.0, black_box(end_point), end_angle));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, shortest_length_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,191 @@
+//! Computation of the shortest paths for many pose pairs at once
+//!
+//! Every start pose is paired with the end pose at the same index.
+//! The paths are expressed in the frame of their start pose, that is
+//! the start pose is (0,0) facing in positive y-direction like for `get_shortest`.
+//!
+//! The output buffers are cleared before they get filled so they can be reused
+//! between calls without reallocating.
+//!
+//! With the `rayon` feature enabled the `par_` variants spread the work across all cores.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{get_shortest, shortest_length, Error, Path, Pose};
+
+/// get the length of the shortest path from the start pose to the end pose
+fn shortest_length_between<T>(radius: T, start: &Pose<T>, end: &Pose<T>) -> T
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let end = end.relative_to(start);
+    shortest_length(radius, end.point, end.angle)
+}
+
+/// get the shortest path from the start pose to the end pose in the frame of the start pose
+fn shortest_between<T>(radius: T, start: &Pose<T>, end: &Pose<T>) -> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let end = end.relative_to(start);
+    get_shortest(radius, end.point, end.angle)
+}
+
+/// fill the lengths buffer with the length of the shortest path of every pose pair
+pub fn shortest_lengths<T>(
+    radius: T,
+    starts: &[Pose<T>],
+    ends: &[Pose<T>],
+    lengths: &mut Vec<T>,
+) -> Result<(), Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if starts.len() != ends.len() {
+        return Err(Error::PoseCountMismatch);
+    }
+
+    lengths.clear();
+    lengths.extend(
+        starts
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| shortest_length_between(radius, start, end)),
+    );
+
+    Ok(())
+}
+
+/// fill the paths buffer with the shortest path of every pose pair
+pub fn shortest_paths<T>(
+    radius: T,
+    starts: &[Pose<T>],
+    ends: &[Pose<T>],
+    paths: &mut Vec<Path<T>>,
+) -> Result<(), Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if starts.len() != ends.len() {
+        return Err(Error::PoseCountMismatch);
+    }
+
+    paths.clear();
+    paths.extend(
+        starts
+            .iter()
+            .zip(ends)
+            .map(|(start, end)| shortest_between(radius, start, end)),
+    );
+
+    Ok(())
+}
+
+/// parallel version of `shortest_lengths`
+#[cfg(feature = "rayon")]
+pub fn par_shortest_lengths<T>(
+    radius: T,
+    starts: &[Pose<T>],
+    ends: &[Pose<T>],
+    lengths: &mut Vec<T>,
+) -> Result<(), Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Send
+        + Sync,
+{
+    if starts.len() != ends.len() {
+        return Err(Error::PoseCountMismatch);
+    }
+
+    lengths.clear();
+    lengths.par_extend(
+        starts
+            .par_iter()
+            .zip(ends)
+            .map(|(start, end)| shortest_length_between(radius, start, end)),
+    );
+
+    Ok(())
+}
+
+/// parallel version of `shortest_paths`
+#[cfg(feature = "rayon")]
+pub fn par_shortest_paths<T>(
+    radius: T,
+    starts: &[Pose<T>],
+    ends: &[Pose<T>],
+    paths: &mut Vec<Path<T>>,
+) -> Result<(), Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Send
+        + Sync,
+{
+    if starts.len() != ends.len() {
+        return Err(Error::PoseCountMismatch);
+    }
+
+    paths.clear();
+    paths.par_extend(
+        starts
+            .par_iter()
+            .zip(ends)
+            .map(|(start, end)| shortest_between(radius, start, end)),
+    );
+
+    Ok(())
+}
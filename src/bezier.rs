@@ -0,0 +1,287 @@
+//! Cubic Bezier curves approximating paths
+//!
+//! Straights are exact cubic Bezier curves. Circles are divided into the fewest
+//! pieces (of at most a quarter circle) whose Bezier curves stay within the
+//! tolerance. The control points of a piece over the angle φ lie on the tangents
+//! at its ends, 4/3 tan(φ/4) r away from them, which deviates at most
+//! r 4/27 sin⁶(φ/4) / cos²(φ/4) from the circle (Goldapp, 1991).
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    rotate_around, segment_path::SegmentPath, CirclePath, Direction, Error, Path, Point, Segment,
+    StraightPath,
+};
+
+/// Cubic Bezier curve
+#[derive(Debug, Copy, Clone)]
+pub struct CubicBezier<T> {
+    pub start: Point<T>,
+    pub first_control: Point<T>,
+    pub second_control: Point<T>,
+    pub end: Point<T>,
+}
+
+impl<T> CubicBezier<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new cubic bezier curve
+    pub fn new(
+        start: Point<T>,
+        first_control: Point<T>,
+        second_control: Point<T>,
+        end: Point<T>,
+    ) -> Self {
+        Self {
+            start,
+            first_control,
+            second_control,
+            end,
+        }
+    }
+
+    /// get the point of the curve at the parameter (from 0 at the start to 1 at the end)
+    pub fn point_at(&self, parameter: T) -> Point<T> {
+        let three: T = 3.0.into();
+        let rest = T::one() - parameter;
+
+        let weights = [
+            rest * rest * rest,
+            three * rest * rest * parameter,
+            three * rest * parameter * parameter,
+            parameter * parameter * parameter,
+        ];
+        let points = [
+            self.start,
+            self.first_control,
+            self.second_control,
+            self.end,
+        ];
+
+        weights
+            .iter()
+            .zip(points.iter())
+            .fold(Point::new(T::zero(), T::zero()), |sum, (weight, point)| {
+                sum + point.to_vector() * *weight
+            })
+    }
+}
+
+/// get the largest angle of a piece whose bezier curve deviates at most the
+/// (relative) tolerance from a circle with radius one
+///
+/// with u = sin²(φ/4) the deviation is 4/27 u³ / (1 - u), so u is the real root
+/// of u³ + k u - k = 0 with k = 27/4 tolerance
+fn max_angle<T>(tolerance: T) -> T
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let k = tolerance * (27.0 / 4.0);
+    let (two, three): (T, T) = (2.0.into(), 3.0.into());
+
+    // hyperbolic form of the only real root, it doesn't cancel for small tolerances
+    let scale = two * Float::sqrt(k / three);
+    let u = scale * Float::sinh(Float::asinh(Float::sqrt(three / k) * 1.5) / three);
+
+    Float::asin(Float::sqrt(u.min(T::one()))) * 4.0
+}
+
+impl<T> StraightPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the bezier curve of the straight
+    pub fn to_bezier(&self) -> CubicBezier<T> {
+        let three: T = 3.0.into();
+        let third = self.vector / three;
+        let end = self.origin + self.vector;
+        CubicBezier::new(self.origin, self.origin + third, end - third, end)
+    }
+}
+
+impl<T> CirclePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the fewest bezier curves deviating at most the tolerance from the circle
+    /// if the circle starts at the start point and turns in the direction
+    ///
+    /// returns an error if the tolerance is not positive or smaller than the
+    /// precision of the floating point numbers relative to the radius
+    pub fn to_beziers(
+        &self,
+        start: Point<T>,
+        direction: Direction,
+        tolerance: T,
+    ) -> Result<Vec<CubicBezier<T>>, Error> {
+        // smaller deviations are lost in the rounding of the points
+        if tolerance.is_nan() || tolerance < self.radius * T::epsilon() || tolerance <= T::zero() {
+            return Err(Error::InvalidTolerance);
+        }
+
+        // at least one piece per quarter circle
+        let max_angle = max_angle(tolerance / self.radius).min(T::FRAC_PI_2());
+        let pieces = (self.angle.radians / max_angle)
+            .ceil()
+            .to_usize()
+            .unwrap_or(0)
+            .max(1);
+        let piece_count: T = (pieces as f64).into();
+
+        let angle = self.angle.radians / piece_count;
+        let handle = Float::tan(angle / 4.0.into()) * (4.0 / 3.0);
+
+        Ok((0..pieces)
+            .map(|index| {
+                let index: T = (index as f64).into();
+                let turn = |angle: T| {
+                    rotate_around(start, self.center, direction.signed(Angle::radians(angle)))
+                };
+                let (from, to) = (turn(angle * index), turn(angle * (index + T::one())));
+
+                CubicBezier::new(
+                    from,
+                    from + self.heading_at(from, direction) * handle,
+                    to - self.heading_at(to, direction) * handle,
+                    to,
+                )
+            })
+            .collect())
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the fewest bezier curves deviating at most the tolerance from the segment
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_beziers(&self, tolerance: T) -> Result<Vec<CubicBezier<T>>, Error> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => circle.to_beziers(*start, *direction, tolerance),
+            Self::Straight(straight) => {
+                if tolerance.is_nan() || tolerance <= T::zero() {
+                    return Err(Error::InvalidTolerance);
+                }
+                Ok(vec![straight.to_bezier()])
+            }
+        }
+    }
+}
+
+/// join the bezier curves of the segments, leaving out segments without length
+fn join<T>(segments: &[Segment<T>], tolerance: T) -> Result<Vec<CubicBezier<T>>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let mut beziers = Vec::new();
+    for segment in segments.iter() {
+        let segment_beziers = segment.to_beziers(tolerance)?;
+        if segment.get_length() > T::zero() {
+            beziers.extend(segment_beziers);
+        }
+    }
+    Ok(beziers)
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the fewest bezier curves deviating at most the tolerance from the path
+    /// (segments without length are left out)
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_beziers(&self, tolerance: T) -> Result<Vec<CubicBezier<T>>, Error> {
+        join(&self.segments(), tolerance)
+    }
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the fewest bezier curves deviating at most the tolerance from the path
+    /// (segments without length are left out)
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_beziers(&self, tolerance: T) -> Result<Vec<CubicBezier<T>>, Error> {
+        if tolerance.is_nan() || tolerance <= T::zero() {
+            return Err(Error::InvalidTolerance);
+        }
+        join(&self.segments, tolerance)
+    }
+}
@@ -0,0 +1,251 @@
+//! Bounding boxes and convex hulls of paths
+//!
+//! The bounding box is exact: besides the start and end points of a circle it
+//! contains the points where the circle crosses the axis directions as seen from
+//! its center (its leftmost, rightmost, lowest and highest points).
+//!
+//! The convex hull is conservative: every circle is enclosed by the polygon of
+//! its tangents at the ends of pieces of at most a quarter circle, so the hull
+//! contains the whole path and is slightly larger around the circles.
+
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul},
+};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    rotate_around, segment_path::SegmentPath, BoundingBox, Direction, Path, Point, Segment,
+    StraightPath, Vector,
+};
+
+impl<T> StraightPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the smallest axis aligned box containing the straight
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        BoundingBox::from_points(&[self.origin, self.origin + self.vector])
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the smallest axis aligned box containing the segment
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                let end = rotate_around(*start, circle.center, direction.signed(circle.angle));
+
+                // the counter clockwise angle from the positive x-axis where the circle
+                // starts when it is driven counter clockwise
+                let low = match direction {
+                    Direction::Left => *start,
+                    Direction::Right => end,
+                } - circle.center;
+                let low = Angle::radians(low.y.atan2(low.x));
+
+                let mut points = vec![*start, end];
+                for (quarter, offset) in [
+                    (T::zero(), (circle.radius, T::zero())),
+                    (T::FRAC_PI_2(), (T::zero(), circle.radius)),
+                    (T::PI(), (-circle.radius, T::zero())),
+                    (T::PI() + T::FRAC_PI_2(), (T::zero(), -circle.radius)),
+                ]
+                .iter()
+                {
+                    if (Angle::radians(*quarter) - low).positive() <= circle.angle {
+                        points.push(circle.center + Vector::new(offset.0, offset.1));
+                    }
+                }
+
+                BoundingBox::from_points(&points)
+            }
+            Self::Straight(straight) => straight.bounding_box(),
+        }
+    }
+
+    /// get points whose convex hull contains the segment
+    ///
+    /// for a circle these are the ends of pieces of at most a quarter circle and
+    /// the intersections of the tangents at the ends of every piece
+    fn hull_points(&self) -> Vec<Point<T>> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                let pieces = (circle.angle.radians / T::FRAC_PI_2())
+                    .ceil()
+                    .to_usize()
+                    .unwrap_or(0)
+                    .max(1);
+                let piece_count: T = (pieces as f64).into();
+                let piece = circle.angle.radians / piece_count;
+                let half: T = piece / 2.0.into();
+
+                let mut points = vec![*start];
+                for index in 0..pieces {
+                    let index: T = (index as f64).into();
+                    let middle = rotate_around(
+                        *start,
+                        circle.center,
+                        direction.signed(Angle::radians(piece * index + half)),
+                    );
+                    // the tangents meet on the line through the middle of the piece
+                    points.push(circle.center + (middle - circle.center) / Float::cos(half));
+                    points.push(rotate_around(
+                        *start,
+                        circle.center,
+                        direction.signed(Angle::radians(piece * index + piece)),
+                    ));
+                }
+                points
+            }
+            Self::Straight(straight) => vec![straight.origin, straight.origin + straight.vector],
+        }
+    }
+
+    /// get a convex polygon (counter clockwise) containing the segment
+    pub fn convex_hull(&self) -> Vec<Point<T>> {
+        convex_hull(self.hull_points())
+    }
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the smallest axis aligned box containing the path
+    pub fn bounding_box(&self) -> BoundingBox<T> {
+        let [first, second, third] = self.segments();
+        first
+            .bounding_box()
+            .union(&second.bounding_box())
+            .union(&third.bounding_box())
+    }
+
+    /// get a convex polygon (counter clockwise) containing the path
+    pub fn convex_hull(&self) -> Vec<Point<T>> {
+        convex_hull(
+            self.segments()
+                .iter()
+                .flat_map(Segment::hull_points)
+                .collect(),
+        )
+    }
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the smallest axis aligned box containing the path
+    /// (none for a path without segments)
+    pub fn bounding_box(&self) -> Option<BoundingBox<T>> {
+        self.segments
+            .iter()
+            .map(Segment::bounding_box)
+            .fold(None, |bounds, current| match bounds {
+                Some(bounds) => Some(current.union(&bounds)),
+                None => Some(current),
+            })
+    }
+
+    /// get a convex polygon (counter clockwise) containing the path
+    /// (empty for a path without segments)
+    pub fn convex_hull(&self) -> Vec<Point<T>> {
+        convex_hull(
+            self.segments
+                .iter()
+                .flat_map(Segment::hull_points)
+                .collect(),
+        )
+    }
+}
+
+/// get the convex hull (counter clockwise, starting at the lowest leftmost point)
+/// of the points with the monotone chain algorithm
+///
+/// points on the edges of the hull are left out
+fn convex_hull<T: Float>(mut points: Vec<Point<T>>) -> Vec<Point<T>> {
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // the lower hull from left to right and the upper hull back,
+    // each without its last point which is the first one of the other
+    let mut hull = half_hull(points.iter());
+    hull.pop();
+    let mut upper = half_hull(points.iter().rev());
+    upper.pop();
+    hull.extend(upper);
+
+    hull
+}
+
+/// get the chain of the points only turning counter clockwise
+fn half_hull<'a, T: Float + 'a>(points: impl Iterator<Item = &'a Point<T>>) -> Vec<Point<T>> {
+    let mut hull: Vec<Point<T>> = Vec::new();
+
+    for point in points {
+        while hull.len() >= 2 {
+            let last = hull[hull.len() - 1];
+            let before = hull[hull.len() - 2];
+            if (last - before).cross(*point - last) > T::zero() {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(*point);
+    }
+
+    hull
+}
@@ -0,0 +1,615 @@
+//! Continuous curvature paths
+//!
+//! The curvature of Dubins paths jumps between 0 and 1/radius where the circles
+//! meet the tangent. Here every turn is replaced by a clothoid where the curvature
+//! grows with the maximum curvature rate up to 1/radius, a circle and a clothoid
+//! back to curvature 0 (a continuous curvature turn after Fraichard and Scheuer).
+//!
+//! Such a turn starts and ends on a circle around the center of its circle, but
+//! not tangential to it, so the words are solved on these circles instead of
+//! the circles of the turns. The paths are built from the RSR, RSL, LSR, LSL,
+//! RLR and LRL words like the Dubins paths and still connect the requested poses.
+//!
+//! Turns that are shorter than the two clothoids (but not zero) cannot be driven
+//! and are extended by as many full loops as needed, so the paths are not always
+//! the shortest continuous curvature paths.
+//!
+//! The poses on the clothoids are computed with the Fresnel integrals.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Direction, Error, PathType, Point, Pose, Vector};
+
+/// largest argument of the Fresnel integrals computed with the power series
+const FRESNEL_SERIES_LIMIT: f64 = 1.5;
+
+/// maximum number of terms of the power series and the continued fraction
+const FRESNEL_MAX_TERMS: usize = 200;
+
+/// Fresnel integrals C(u) and S(u) (∫ cos(πt²/2) and ∫ sin(πt²/2) from 0 to u)
+///
+/// small arguments use the power series, larger ones the asymptotic expansion of
+/// the complementary error function evaluated as a continued fraction (modified
+/// Lentz), whose terms lose no precision for large arguments
+fn fresnel<T: Float + FloatConst>(u: T) -> (T, T) {
+    let limit = T::from(FRESNEL_SERIES_LIMIT).unwrap();
+    let (c, s) = if u.abs() < limit {
+        fresnel_series(u.abs())
+    } else {
+        fresnel_asymptotic(u.abs())
+    };
+    // both integrals are odd functions
+    if u < T::zero() {
+        (-c, -s)
+    } else {
+        (c, s)
+    }
+}
+
+/// Fresnel integrals of a small non negative argument from their power series
+fn fresnel_series<T: Float + FloatConst>(u: T) -> (T, T) {
+    let x = T::FRAC_PI_2() * u * u;
+    let (mut c, mut s) = (T::zero(), T::zero());
+    // x^k / k!
+    let mut power = T::one();
+    let mut k = 0;
+
+    loop {
+        let term = power * u / T::from(2 * k + 1).unwrap();
+        let term = if (k / 2) % 2 == 0 { term } else { -term };
+        if k % 2 == 0 {
+            c = c + term;
+        } else {
+            s = s + term;
+        }
+
+        k += 1;
+        power = power * x / T::from(k).unwrap();
+        if (T::from(k).unwrap() > x && power.abs() < T::epsilon()) || k > FRESNEL_MAX_TERMS {
+            return (c, s);
+        }
+    }
+}
+
+/// product of two complex numbers given as real and imaginary part
+fn complex_mul<T: Float>((a, b): (T, T), (c, d): (T, T)) -> (T, T) {
+    (a * c - b * d, a * d + b * c)
+}
+
+/// inverse of a complex number given as real and imaginary part
+fn complex_inv<T: Float>((a, b): (T, T)) -> (T, T) {
+    let square = a * a + b * b;
+    (a / square, -b / square)
+}
+
+/// Fresnel integrals of a large positive argument
+///
+/// C + iS = (1 + i)/2 (1 - e^(iπu²/2) h) with the continued fraction
+/// h = (1 - i) u / (b - 1·2/(b + 4 - 3·4/(b + 8 - ...))) and b = 1 - iπu²
+fn fresnel_asymptotic<T: Float + FloatConst>(u: T) -> (T, T) {
+    let four = T::from(4).unwrap();
+    let tiny = T::min_positive_value() / T::epsilon();
+    let square = T::PI() * u * u;
+
+    let mut b = (T::one(), -square);
+    // ratios of the successive numerators and denominators of the convergents
+    let mut numerators = (tiny.recip(), T::zero());
+    let mut denominators = complex_inv(b);
+    let mut h = denominators;
+
+    for n in (1..FRESNEL_MAX_TERMS).step_by(2) {
+        let a = -T::from(n * (n + 1)).unwrap();
+        b = (b.0 + four, b.1);
+        denominators = complex_inv((a * denominators.0 + b.0, a * denominators.1 + b.1));
+        let inverse = complex_inv(numerators);
+        numerators = (b.0 + a * inverse.0, b.1 + a * inverse.1);
+        let change = complex_mul(numerators, denominators);
+        h = complex_mul(h, change);
+        if (change.0 - T::one()).abs() + change.1.abs() < T::epsilon() {
+            break;
+        }
+    }
+
+    let h = complex_mul(h, (u, -u));
+    let half = T::one() / (T::one() + T::one());
+    let (sin, cos) = (square * half).sin_cos();
+    let rest = complex_mul((cos, sin), h);
+    complex_mul((half, half), (T::one() - rest.0, -rest.1))
+}
+
+/// heading counter clockwise from the positive x-axis of a pose heading
+fn to_math_angle<T: Float + FloatConst>(angle: Angle<T>) -> T {
+    T::FRAC_PI_2() - angle.radians
+}
+
+/// pose heading of a heading counter clockwise from the positive x-axis
+fn from_math_angle<T: Float + FloatConst>(angle: T) -> Angle<T> {
+    Angle::radians(T::FRAC_PI_2() - angle).positive()
+}
+
+/// rotate the vector counter clockwise by the angle
+fn rotate<T: Float>(vector: Vector<T>, angle: T) -> Vector<T> {
+    let (sin, cos) = angle.sin_cos();
+    Vector::new(
+        vector.x * cos - vector.y * sin,
+        vector.x * sin + vector.y * cos,
+    )
+}
+
+/// wrap an angle in radians into [0, 2π)
+fn wrap<T: Float + FloatConst>(angle: T) -> T {
+    let tau = T::PI() + T::PI();
+    angle - tau * (angle / tau).floor()
+}
+
+/// Part of a continuous curvature path where the curvature changes linearly
+///
+/// with a constant curvature this is a circle or a straight (curvature 0)
+#[derive(Debug, Copy, Clone)]
+pub struct ClothoidSegment<T> {
+    pub start: Pose<T>,
+    pub length: T,
+    /// curvature at the start (positive when turning left)
+    pub start_curvature: T,
+    /// curvature at the end (positive when turning left)
+    pub end_curvature: T,
+}
+
+impl<T> ClothoidSegment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the curvature after driving the distance along the segment
+    pub fn curvature_at(&self, distance: T) -> T {
+        if self.length > T::zero() {
+            self.start_curvature
+                + (self.end_curvature - self.start_curvature) * distance / self.length
+        } else {
+            self.start_curvature
+        }
+    }
+
+    /// get the pose after driving the distance along the segment
+    pub fn pose_at(&self, distance: T) -> Pose<T> {
+        let angle = to_math_angle(self.start.angle);
+        let curvature = self.start_curvature;
+        let sharpness = if self.length > T::zero() {
+            (self.end_curvature - self.start_curvature) / self.length
+        } else {
+            T::zero()
+        };
+        let end_angle = angle + curvature * distance + sharpness * distance * distance / 2.0.into();
+
+        let offset = if sharpness.abs() > T::epsilon() {
+            // θ(t) = α + c/2 (t + κ/c)², so the offset is a difference of Fresnel integrals
+            let scale = (T::PI() / sharpness.abs()).sqrt();
+            let shift = curvature / sharpness;
+            let alpha = angle - curvature * shift / 2.0.into();
+            let (c0, s0) = fresnel(shift / scale);
+            let (c1, s1) = fresnel((shift + distance) / scale);
+            let (c, s) = ((c1 - c0) * scale, (s1 - s0) * scale * sharpness.signum());
+            let (sin, cos) = alpha.sin_cos();
+            Vector::new(cos * c - sin * s, sin * c + cos * s)
+        } else if curvature.abs() > T::epsilon() {
+            Vector::new(
+                (Float::sin(end_angle) - Float::sin(angle)) / curvature,
+                (Float::cos(angle) - Float::cos(end_angle)) / curvature,
+            )
+        } else {
+            Vector::new(Float::cos(angle), Float::sin(angle)) * distance
+        };
+
+        Pose::new(self.start.point + offset, from_math_angle(end_angle))
+    }
+}
+
+/// Continuous curvature path made of clothoids, circles and straights
+#[derive(Debug, Clone)]
+pub struct ClothoidPath<T> {
+    pub path_type: PathType,
+    pub segments: Vec<ClothoidSegment<T>>,
+}
+
+impl<T> ClothoidPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the total length of the path
+    pub fn get_length(&self) -> T {
+        self.segments
+            .iter()
+            .fold(T::zero(), |length, segment| length + segment.length)
+    }
+
+    /// get the segment the distance along the path lies on
+    /// and the distance from the start of that segment
+    fn segment_at(&self, distance: T) -> (&ClothoidSegment<T>, T) {
+        let mut distance = distance.max(T::zero());
+        let last = self.segments.len() - 1;
+
+        for segment in self.segments[..last].iter() {
+            if distance <= segment.length {
+                return (segment, distance);
+            }
+            distance = distance - segment.length;
+        }
+
+        let segment = &self.segments[last];
+        (segment, distance.min(segment.length))
+    }
+
+    /// get the pose after driving the distance along the path (clamped to the path)
+    pub fn pose_at(&self, distance: T) -> Pose<T> {
+        let (segment, distance) = self.segment_at(distance);
+        segment.pose_at(distance)
+    }
+
+    /// get the curvature after driving the distance along the path (clamped to the path)
+    pub fn curvature_at(&self, distance: T) -> T {
+        let (segment, distance) = self.segment_at(distance);
+        segment.curvature_at(distance)
+    }
+
+    /// get poses along the path at most the step apart including the start and end pose
+    pub fn sample(&self, step: T) -> Vec<Pose<T>> {
+        let length = self.get_length();
+        let count = (length / step).ceil().to_usize().unwrap_or(0).max(1);
+
+        (0..=count)
+            .map(|index| {
+                let fraction: T = (index as f64 / count as f64).into();
+                self.pose_at(length * fraction)
+            })
+            .collect()
+    }
+}
+
+/// Shape of the continuous curvature turns
+#[derive(Debug, Copy, Clone)]
+struct Turn<T> {
+    /// maximum curvature
+    curvature: T,
+    /// length of one clothoid
+    clothoid_length: T,
+    /// heading change of one clothoid
+    clothoid_angle: T,
+    /// center of the circle of a left turn starting at (0,0) facing in positive x-direction
+    center: Vector<T>,
+}
+
+impl<T> Turn<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    fn new(radius: T, curvature_rate: T) -> Self {
+        let curvature = radius.recip();
+        let clothoid_length = curvature / curvature_rate;
+        let clothoid_angle = curvature * clothoid_length / 2.0.into();
+
+        let clothoid = ClothoidSegment {
+            start: Pose::new(Point::new(T::zero(), T::zero()), from_math_angle(T::zero())),
+            length: clothoid_length,
+            start_curvature: T::zero(),
+            end_curvature: curvature,
+        };
+        let end = clothoid.pose_at(clothoid_length).point;
+        let (sin, cos) = clothoid_angle.sin_cos();
+
+        Self {
+            curvature,
+            clothoid_length,
+            clothoid_angle,
+            center: end.to_vector() + Vector::new(-sin, cos) * radius,
+        }
+    }
+
+    /// the circle center of a turn in the direction starting at the point with the heading
+    fn start_center(&self, point: Point<T>, angle: T, direction: Direction) -> Point<T> {
+        let side = direction.signed(Angle::radians(T::one())).radians;
+        point + rotate(Vector::new(self.center.x, side * self.center.y), angle)
+    }
+
+    /// the circle center of a turn in the direction ending at the point with the heading
+    fn end_center(&self, point: Point<T>, angle: T, direction: Direction) -> Point<T> {
+        let side = direction.signed(Angle::radians(T::one())).radians;
+        point + rotate(Vector::new(-self.center.x, side * self.center.y), angle)
+    }
+
+    /// get the heading change of a turn that can be driven, or None for no turn at all
+    fn feasible(&self, angle: T) -> Option<T> {
+        let tau = T::PI() + T::PI();
+        let angle = wrap(angle);
+        let tolerance = T::epsilon().sqrt();
+        let minimum = self.clothoid_angle + self.clothoid_angle;
+
+        if angle < tolerance || tau - angle < tolerance {
+            None
+        } else if angle < minimum - tolerance {
+            // too short for the clothoids, so turn as many full loops more as needed
+            let loops = ((minimum - tolerance - angle) / tau).ceil();
+            Some((angle + tau * loops).max(minimum))
+        } else {
+            Some(angle.max(minimum))
+        }
+    }
+
+    /// get the length of a turn with the heading change
+    fn length(&self, angle: Option<T>) -> T {
+        match angle {
+            Some(angle) => {
+                let arc = angle - self.clothoid_angle - self.clothoid_angle;
+                self.clothoid_length + self.clothoid_length + arc / self.curvature
+            }
+            // without a heading change the turn is a straight through the circle
+            None => self.center.x + self.center.x,
+        }
+    }
+
+    /// get the curvatures and lengths of the segments of a turn with the heading change
+    fn segments(&self, angle: Option<T>, direction: Direction) -> Vec<(T, T, T)> {
+        let curvature = direction.signed(Angle::radians(self.curvature)).radians;
+
+        match angle {
+            Some(angle) => {
+                let arc = (angle - self.clothoid_angle - self.clothoid_angle) / self.curvature;
+                let mut segments = vec![(self.clothoid_length, T::zero(), curvature)];
+                if arc > T::zero() {
+                    segments.push((arc, curvature, curvature));
+                }
+                segments.push((self.clothoid_length, curvature, T::zero()));
+                segments
+            }
+            None => vec![(self.length(None), T::zero(), T::zero())],
+        }
+    }
+}
+
+/// Continuous curvature path before its segments are built
+struct Candidate<T> {
+    path_type: PathType,
+    turns: Vec<(Option<T>, Direction)>,
+    straight: T,
+    length: T,
+}
+
+/// get the continuous curvature path with the turns of the word
+fn csc<T>(
+    turn: &Turn<T>,
+    path_type: PathType,
+    start_center: Point<T>,
+    start_angle: T,
+    end_center: Point<T>,
+    end_angle: T,
+) -> Option<Candidate<T>>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let start_direction = path_type.start_direction();
+    let end_direction = path_type.end_direction();
+    let start_side = start_direction.signed(Angle::radians(T::one())).radians;
+    let end_side = end_direction.signed(Angle::radians(T::one())).radians;
+
+    // the centers are apart by (straight + 2 x) along and (end side - start side) y across the straight
+    let centers = end_center - start_center;
+    let across = (end_side - start_side) * turn.center.y;
+    let along_squared = centers.square_length() - across * across;
+    if along_squared < T::zero() {
+        return None;
+    }
+    let along = along_squared.sqrt();
+    let straight = along - turn.center.x - turn.center.x;
+    if straight < -T::epsilon().sqrt() {
+        return None;
+    }
+    let straight = straight.max(T::zero());
+    let straight_angle = centers.y.atan2(centers.x) - across.atan2(along);
+
+    let first = turn.feasible(start_side * (straight_angle - start_angle));
+    let last = turn.feasible(end_side * (end_angle - straight_angle));
+
+    Some(Candidate {
+        path_type,
+        turns: vec![(first, start_direction), (last, end_direction)],
+        straight,
+        length: turn.length(first) + straight + turn.length(last),
+    })
+}
+
+/// get the continuous curvature paths with three turns of the word
+/// (there are two middle circles touching both circles)
+fn ccc<T>(
+    turn: &Turn<T>,
+    path_type: PathType,
+    start_center: Point<T>,
+    start_angle: T,
+    end_center: Point<T>,
+    end_angle: T,
+) -> Vec<Candidate<T>>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let direction = path_type.start_direction();
+    let side = direction.signed(Angle::radians(T::one())).radians;
+    let radius = turn.center.length();
+
+    let centers = end_center - start_center;
+    let distance = centers.length();
+    let four: T = 4.0.into();
+    if distance > four * radius {
+        return Vec::new();
+    }
+
+    // heading of the junction relative to the line between the centers
+    let junction = turn.center.y.atan2(turn.center.x);
+    let center_angle = centers.y.atan2(centers.x);
+    let offset = (distance / (four * radius)).min(T::one()).acos();
+
+    [center_angle + offset, center_angle - offset]
+        .iter()
+        .map(|&angle| {
+            let middle_center = start_center
+                + Vector::new(Float::cos(angle), Float::sin(angle)) * (radius + radius);
+            let to_end = end_center - middle_center;
+            let first_angle = angle + side * junction;
+            let second_angle = to_end.y.atan2(to_end.x) - side * junction;
+
+            let first = turn.feasible(side * (first_angle - start_angle));
+            let middle = turn.feasible(-side * (second_angle - first_angle));
+            let last = turn.feasible(side * (end_angle - second_angle));
+
+            Candidate {
+                path_type,
+                turns: vec![
+                    (first, direction),
+                    (middle, direction.opposite()),
+                    (last, direction),
+                ],
+                straight: T::zero(),
+                length: turn.length(first) + turn.length(middle) + turn.length(last),
+            }
+        })
+        .collect()
+}
+
+/// get the shortest continuous curvature path
+///
+/// the start is (0,0) facing in positive y-direction like for `get_shortest`,
+/// the curvature is at most 1/radius and changes at most by the curvature rate per length
+pub fn shortest_clothoid_path<T>(
+    radius: T,
+    max_curvature_rate: T,
+    end_point: Point<T>,
+    end_angle: Angle<T>,
+) -> Result<ClothoidPath<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if radius.is_nan()
+        || radius <= T::zero()
+        || max_curvature_rate.is_nan()
+        || max_curvature_rate <= T::zero()
+    {
+        return Err(Error::InvalidLimits);
+    }
+
+    let turn = Turn::new(radius, max_curvature_rate);
+    let start = Pose::new(Point::new(T::zero(), T::zero()), Angle::zero());
+    let start_angle = to_math_angle(start.angle);
+    let end_angle = to_math_angle(end_angle);
+
+    let mut candidates = Vec::new();
+    for &path_type in PathType::ALL.iter() {
+        let start_center = turn.start_center(start.point, start_angle, path_type.start_direction());
+        let end_center = turn.end_center(end_point, end_angle, path_type.end_direction());
+
+        if path_type.is_csc() {
+            candidates.extend(csc(
+                &turn,
+                path_type,
+                start_center,
+                start_angle,
+                end_center,
+                end_angle,
+            ));
+        } else {
+            candidates.extend(ccc(
+                &turn,
+                path_type,
+                start_center,
+                start_angle,
+                end_center,
+                end_angle,
+            ));
+        }
+    }
+
+    // there is always either a lsl path or a lrl path
+    let shortest = candidates
+        .into_iter()
+        .fold(
+            None,
+            |shortest: Option<Candidate<T>>, candidate| match shortest {
+                Some(shortest) if shortest.length <= candidate.length => Some(shortest),
+                _ => Some(candidate),
+            },
+        )
+        .unwrap();
+
+    // build the segments turn by turn with the straight between the turns of a csc path
+    let mut shapes = Vec::new();
+    for (index, &(angle, direction)) in shortest.turns.iter().enumerate() {
+        if index == 1 && shortest.path_type.is_csc() && shortest.straight > T::zero() {
+            shapes.push((shortest.straight, T::zero(), T::zero()));
+        }
+        shapes.extend(turn.segments(angle, direction));
+    }
+
+    let mut segments: Vec<ClothoidSegment<T>> = Vec::with_capacity(shapes.len());
+    let mut pose = start;
+    for (length, start_curvature, end_curvature) in shapes {
+        let segment = ClothoidSegment {
+            start: pose,
+            length,
+            start_curvature,
+            end_curvature,
+        };
+        pose = segment.pose_at(length);
+        segments.push(segment);
+    }
+
+    Ok(ClothoidPath {
+        path_type: shortest.path_type,
+        segments,
+    })
+}
@@ -0,0 +1,133 @@
+//! Command schedules for driving a path with a constant speed
+//!
+//! Every segment of the path has a constant curvature, so it is driven with a
+//! single command:
+//!
+//!   - car-like vehicles (bicycle model) steer the front wheels by
+//!     atan(wheelbase * curvature)
+//!   - differential drive vehicles drive the wheels with
+//!     speed * (1 ∓ curvature * track_width / 2)
+//!
+//! Segments without length (e.g. a tangent of length zero) don't get a command.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Error, Path};
+
+/// Steering command of a car-like vehicle
+#[derive(Debug, Copy, Clone)]
+pub struct AckermannCommand<T> {
+    /// time since the start of the path
+    pub start_time: T,
+    pub duration: T,
+    pub speed: T,
+    /// steering angle of the front wheels (positive when turning left)
+    pub steering_angle: Angle<T>,
+}
+
+/// Wheel speed command of a differential drive vehicle
+#[derive(Debug, Copy, Clone)]
+pub struct DifferentialDriveCommand<T> {
+    /// time since the start of the path
+    pub start_time: T,
+    pub duration: T,
+    pub left_speed: T,
+    pub right_speed: T,
+}
+
+/// get the start time, duration and curvature of every segment with a length
+fn schedule<T>(path: &Path<T>, speed: T) -> Result<Vec<(T, T, T)>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if speed.is_nan() || speed <= T::zero() {
+        return Err(Error::InvalidSpeed);
+    }
+
+    let mut start_time = T::zero();
+    let mut schedule = Vec::new();
+
+    for segment in path.segments().iter() {
+        let length = segment.get_length();
+        if length > T::zero() {
+            let duration = length / speed;
+            schedule.push((start_time, duration, segment.curvature()));
+            start_time = start_time + duration;
+        }
+    }
+
+    Ok(schedule)
+}
+
+/// get the commands to drive the path with the speed with a car-like vehicle
+/// with the wheelbase (distance between the front and the rear axle)
+pub fn ackermann_commands<T>(
+    path: &Path<T>,
+    speed: T,
+    wheelbase: T,
+) -> Result<Vec<AckermannCommand<T>>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    Ok(schedule(path, speed)?
+        .into_iter()
+        .map(|(start_time, duration, curvature)| AckermannCommand {
+            start_time,
+            duration,
+            speed,
+            steering_angle: Angle::radians(Float::atan(wheelbase * curvature)),
+        })
+        .collect())
+}
+
+/// get the commands to drive the path with the speed with a differential drive vehicle
+/// with the track width (distance between the left and the right wheel)
+pub fn differential_drive_commands<T>(
+    path: &Path<T>,
+    speed: T,
+    track_width: T,
+) -> Result<Vec<DifferentialDriveCommand<T>>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let half_track_width = track_width / 2.0.into();
+
+    Ok(schedule(path, speed)?
+        .into_iter()
+        .map(
+            |(start_time, duration, curvature)| DifferentialDriveCommand {
+                start_time,
+                duration,
+                left_speed: speed * (T::one() - curvature * half_track_width),
+                right_speed: speed * (T::one() + curvature * half_track_width),
+            },
+        )
+        .collect())
+}
@@ -0,0 +1,124 @@
+//! Path tracking controllers
+//!
+//! Both controllers take the current pose of the vehicle and the path it should
+//! follow and return the curvature to steer with. The curvature is the inverse of
+//! the turning radius and positive when turning left (counter clockwise).
+//!
+//! The closest point and the lookahead point are computed analytically on the
+//! circles and the tangent of the path, so no sampling of the path is needed.
+//!
+//!   - [`PurePursuit`] steers onto the circle through the point a lookahead
+//!     distance ahead on the path
+//!   - [`Stanley`] corrects the heading error and the cross track error of the
+//!     front axle and feeds forward the curvature of the path
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Path, Pose, Vector};
+
+/// Pure pursuit controller
+#[derive(Debug, Copy, Clone)]
+pub struct PurePursuit<T> {
+    /// distance along the path from the closest point to the point steered to
+    pub lookahead: T,
+}
+
+impl<T> PurePursuit<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a pure pursuit controller with the lookahead distance
+    pub fn new(lookahead: T) -> Self {
+        Self { lookahead }
+    }
+
+    /// get the curvature to steer with at the pose to follow the path
+    pub fn curvature(&self, pose: &Pose<T>, path: &Path<T>) -> T {
+        let distance = path.project(pose.point).distance;
+        let target = Pose::new(path.pose_at(distance + self.lookahead).point, pose.angle);
+
+        // in the frame of the vehicle it faces in positive y-direction
+        let target = target.relative_to(pose).point;
+        let squared_distance = target.to_vector().square_length();
+
+        if squared_distance > T::zero() {
+            // the circle through the target touching the heading of the vehicle
+            -(target.x + target.x) / squared_distance
+        } else {
+            path.curvature_at(distance)
+        }
+    }
+}
+
+/// Stanley controller
+#[derive(Debug, Copy, Clone)]
+pub struct Stanley<T> {
+    /// gain of the cross track error
+    pub gain: T,
+    /// speed added to the actual speed to keep the controller calm at low speeds
+    pub softening: T,
+    /// distance between the rear axle (the pose of the vehicle) and the front axle
+    pub wheelbase: T,
+    /// maximum steering angle of the front wheels
+    pub max_steering_angle: Angle<T>,
+}
+
+impl<T> Stanley<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a stanley controller
+    pub fn new(gain: T, softening: T, wheelbase: T, max_steering_angle: Angle<T>) -> Self {
+        Self {
+            gain,
+            softening,
+            wheelbase,
+            max_steering_angle,
+        }
+    }
+
+    /// get the steering angle of the front wheels (positive when turning left)
+    /// at the pose driving with the speed to follow the path
+    pub fn steering_angle(&self, pose: &Pose<T>, speed: T, path: &Path<T>) -> Angle<T> {
+        // the heading is clockwise from the positive y-axis
+        let front_axle = pose.point
+            + Vector::from_angle_and_length(Angle::frac_pi_2() - pose.angle, self.wheelbase);
+        let projection = path.project(front_axle);
+
+        // clockwise, so positive if the path turned right of the vehicle
+        let heading_error = (projection.angle - pose.angle).signed();
+        let feed_forward = Float::atan(self.wheelbase * path.curvature_at(projection.distance));
+        let correction = (self.gain * projection.cross_track_error).atan2(self.softening + speed);
+
+        let steering_angle = feed_forward - heading_error.radians + correction;
+        Angle::radians(
+            steering_angle
+                .max(-self.max_steering_angle.radians)
+                .min(self.max_steering_angle.radians),
+        )
+    }
+
+    /// get the curvature to steer with at the pose driving with the speed to follow the path
+    pub fn curvature(&self, pose: &Pose<T>, speed: T, path: &Path<T>) -> T {
+        Float::tan(self.steering_angle(pose, speed, path).radians) / self.wheelbase
+    }
+}
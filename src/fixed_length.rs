@@ -0,0 +1,357 @@
+//! Paths with a given length
+//!
+//! For missions where several vehicles have to arrive at the same time every
+//! vehicle has to drive a path of exactly the same length, which has to be at
+//! least as long as its shortest path.
+//!
+//! The path is elongated in the following order:
+//!
+//!   - by enlarging the radius of the circles of the path
+//!   - by a loiter arc on a circle with the minimum radius before the shortest path
+//!     from the end of the arc, if the length is less than one more circle
+//!   - by a full loiter circle with a larger radius before the shortest path
+//!
+//! A length might not be reachable with a loiter arc if the shortest paths
+//! from the end of the arc jump in length.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    get_shortest, rotate_around, CirclePath, Direction, Error, Path, PathType, Point, Pose,
+    RouteCCC, RouteCSC,
+};
+
+/// number of values tried for the radius or the loiter angle
+const SAMPLES: usize = 256;
+
+/// number of bisection steps to find the exact radius or loiter angle
+const BISECTION_STEPS: usize = 128;
+
+/// Circle driven before the path
+#[derive(Debug, Copy, Clone)]
+pub struct Loiter<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// circle starting at (0,0) facing in positive y-direction
+    pub circle: CirclePath<T>,
+    pub direction: Direction,
+}
+
+/// Path with an optional loiter circle before it
+#[derive(Debug, Copy, Clone)]
+pub struct FixedLengthPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    pub loiter: Option<Loiter<T>>,
+    /// path starting where the loiter circle ends (in the same frame)
+    pub path: Path<T>,
+}
+
+impl<T> FixedLengthPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the total length of the loiter circle and the path
+    pub fn get_length(&self) -> T {
+        self.loiter
+            .map(|loiter| loiter.circle.get_length())
+            .unwrap_or_else(T::zero)
+            + self.path.get_length()
+    }
+}
+
+/// get the path of the path type with the radius
+fn route<T>(
+    path_type: PathType,
+    radius: T,
+    end_point: Point<T>,
+    end_angle: Angle<T>,
+) -> Result<Path<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    Ok(match path_type {
+        PathType::RSR => Path::CSC(RouteCSC::rsr(radius, end_point, end_angle)?),
+        PathType::RSL => Path::CSC(RouteCSC::rsl(radius, end_point, end_angle)?),
+        PathType::LSR => Path::CSC(RouteCSC::lsr(radius, end_point, end_angle)?),
+        PathType::LSL => Path::CSC(RouteCSC::lsl(radius, end_point, end_angle)?),
+        PathType::RLR => Path::CCC(RouteCCC::rlr(radius, end_point, end_angle)?),
+        PathType::LRL => Path::CCC(RouteCCC::lrl(radius, end_point, end_angle)?),
+    })
+}
+
+/// get the radius of the circles of the path
+fn radius_of<T>(path: &Path<T>) -> T
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    match path {
+        Path::CSC(route_csc) => route_csc.start.radius,
+        Path::CCC(route_ccc) => route_ccc.start.radius,
+    }
+}
+
+/// get the loiter circle with the loiter radius turning by the angle in the direction
+/// and the shortest path with the radius from its end to the end pose
+fn loitering<T>(
+    radius: T,
+    loiter_radius: T,
+    angle: Angle<T>,
+    direction: Direction,
+    end: &Pose<T>,
+) -> FixedLengthPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let start = Point::new(T::zero(), T::zero());
+    let center = match direction {
+        Direction::Right => Point::new(loiter_radius, T::zero()),
+        Direction::Left => Point::new(-loiter_radius, T::zero()),
+    };
+
+    // the heading is clockwise, so a left turn decreases it
+    let origin = Pose::new(
+        rotate_around(start, center, direction.signed(angle)),
+        (-direction.signed(angle)).positive(),
+    );
+    let end = end.relative_to(&origin);
+
+    FixedLengthPath {
+        loiter: Some(Loiter {
+            circle: CirclePath {
+                center,
+                radius: loiter_radius,
+                angle,
+            },
+            direction,
+        }),
+        path: get_shortest(radius, end.point, end.angle).placed_at(&origin),
+    }
+}
+
+/// find the first of the parameters where the path has the length
+///
+/// between two parameters where the path is too short and too long the exact
+/// parameter is searched by bisection
+fn find<T, F>(parameters: &[T], length: T, path: F) -> Option<FixedLengthPath<T>>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+    F: Fn(T) -> Option<FixedLengthPath<T>>,
+{
+    let tolerance = T::epsilon().sqrt() * length.max(T::one());
+    let difference = |parameter: T| path(parameter).map(|path| (path, path.get_length() - length));
+
+    let mut previous: Option<(T, T)> = None;
+    for &parameter in parameters {
+        let (path, current) = match difference(parameter) {
+            Some(result) => result,
+            None => {
+                previous = None;
+                continue;
+            }
+        };
+        if current.abs() <= tolerance {
+            return Some(path);
+        }
+
+        if let Some((mut low, low_difference)) = previous {
+            if low_difference.signum() != current.signum() {
+                let mut high = parameter;
+                for _ in 0..BISECTION_STEPS {
+                    let middle = (low + high) / 2.0.into();
+                    match difference(middle) {
+                        Some((_, middle_difference))
+                            if middle_difference.signum() == low_difference.signum() =>
+                        {
+                            low = middle
+                        }
+                        Some(_) => high = middle,
+                        None => break,
+                    }
+                }
+                // the length might also jump between the parameters
+                if let Some((path, difference)) = difference(high) {
+                    if difference.abs() <= tolerance {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+        previous = Some((parameter, current));
+    }
+
+    None
+}
+
+/// get evenly (or geometrically) spaced values from the minimum to the maximum
+fn samples<T>(minimum: T, maximum: T, geometric: bool) -> Vec<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    (0..=SAMPLES)
+        .map(|index| {
+            let fraction: T = (index as f64 / SAMPLES as f64).into();
+            if geometric {
+                minimum * (maximum / minimum).powf(fraction)
+            } else {
+                minimum + (maximum - minimum) * fraction
+            }
+        })
+        .collect()
+}
+
+/// get a path from (0,0) facing in positive y-direction to the end point with the end angle
+/// that is exactly the length long and has circles with at least the radius
+///
+/// returns an error if the length is shorter than the shortest path or if no path
+/// with the length has been found
+pub fn fixed_length_path<T>(
+    radius: T,
+    length: T,
+    end_point: Point<T>,
+    end_angle: Angle<T>,
+) -> Result<FixedLengthPath<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let shortest = get_shortest(radius, end_point, end_angle);
+    let tolerance = T::epsilon().sqrt() * length.max(T::one());
+    if length < shortest.get_length() - tolerance {
+        return Err(Error::LengthTooShort);
+    }
+
+    // enlarge the radius of the path, preferring the smallest radius of all path types
+    let radii = samples(radius, length.max(radius), true);
+    let enlarged = PathType::ALL
+        .iter()
+        .filter_map(|&path_type| {
+            find(&radii, length, |radius| {
+                route(path_type, radius, end_point, end_angle)
+                    .ok()
+                    .map(|path| FixedLengthPath { loiter: None, path })
+            })
+        })
+        .fold(
+            None,
+            |smallest: Option<FixedLengthPath<T>>, path| match smallest {
+                Some(smallest) if radius_of(&smallest.path) <= radius_of(&path.path) => {
+                    Some(smallest)
+                }
+                _ => Some(path),
+            },
+        );
+    if let Some(path) = enlarged {
+        return Ok(path);
+    }
+
+    let end = Pose::new(end_point, end_angle);
+    let difference = length - shortest.get_length();
+    let start_direction = shortest.path_type().start_direction();
+
+    if difference >= (Angle::two_pi() * radius).radians {
+        // a full circle making up the difference returns to the start
+        let loiter_radius = difference / (T::PI() + T::PI());
+        return Ok(loitering(
+            radius,
+            loiter_radius,
+            Angle::two_pi(),
+            start_direction,
+            &end,
+        ));
+    }
+
+    // a loiter arc, first against the first turn of the shortest path
+    let angles = samples(T::zero(), T::PI() + T::PI(), false);
+    [start_direction.opposite(), start_direction]
+        .iter()
+        .filter_map(|&direction| {
+            find(&angles, length, |angle| {
+                Some(loitering(
+                    radius,
+                    radius,
+                    Angle::radians(angle),
+                    direction,
+                    &end,
+                ))
+            })
+        })
+        .next()
+        .ok_or(Error::LengthNotReachable)
+}
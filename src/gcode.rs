@@ -0,0 +1,275 @@
+//! G-code for CNC machines and plotters
+//!
+//! Straights are linear moves (`G1`) and circles are arcs in the XY plane
+//! (`G2` clockwise, `G3` counter clockwise) with the center given relative to
+//! their start point (`I` and `J`). The coordinates are absolute (`G90`) in the
+//! units of the path and moved by the offset of the options, e.g. to the origin
+//! of the work piece.
+//!
+//! A program starts with the units, absolute coordinates and the XY plane and a
+//! rapid move (`G0`) to the start of the path. It doesn't end the program
+//! (`M2`), so programs of several paths can be put together.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    rotate_around, segment_path::SegmentPath, CirclePath, Direction, Error, Path, Point, Segment,
+    StraightPath, Vector,
+};
+
+/// Units of the coordinates
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Units {
+    Millimeters,
+    Inches,
+}
+
+impl Units {
+    /// get the G-code selecting the units
+    fn code(self) -> &'static str {
+        match self {
+            Self::Millimeters => "G21",
+            Self::Inches => "G20",
+        }
+    }
+
+    /// get the number of decimals of the coordinates
+    /// (a micrometer and a tenth of a thousandth of an inch)
+    fn decimals(self) -> usize {
+        match self {
+            Self::Millimeters => 3,
+            Self::Inches => 4,
+        }
+    }
+}
+
+/// Options for emitting G-code
+#[derive(Debug, Copy, Clone)]
+pub struct GCodeOptions<T> {
+    pub units: Units,
+    /// speed of the moves in units per minute
+    pub feedrate: T,
+    /// offset added to all coordinates of the path
+    pub offset: Vector<T>,
+}
+
+impl<T> GCodeOptions<T> {
+    /// create new G-code options
+    pub fn new(units: Units, feedrate: T, offset: Vector<T>) -> Self {
+        Self {
+            units,
+            feedrate,
+            offset,
+        }
+    }
+}
+
+impl<T> GCodeOptions<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// format the number with the decimals of the units
+    /// (without the sign if it is rounded to zero)
+    fn number(&self, value: T) -> String {
+        let text = format!("{:.*}", self.units.decimals(), value);
+        match text.strip_prefix('-') {
+            Some(rest) if rest.chars().all(|c| c == '0' || c == '.') => rest.to_string(),
+            _ => text,
+        }
+    }
+
+    /// format the point moved by the offset
+    fn point(&self, point: Point<T>) -> String {
+        let point = point + self.offset;
+        format!("X{} Y{}", self.number(point.x), self.number(point.y))
+    }
+}
+
+impl<T> StraightPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the linear move (`G1`) to the end of the straight
+    pub fn to_gcode(&self, options: &GCodeOptions<T>) -> String {
+        format!(
+            "G1 {} F{}",
+            options.point(self.origin + self.vector),
+            options.number(options.feedrate)
+        )
+    }
+}
+
+impl<T> CirclePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the arc (`G2` clockwise, `G3` counter clockwise) to the end of the circle
+    /// if it starts at the start point and turns in the direction
+    pub fn to_gcode(
+        &self,
+        start: Point<T>,
+        direction: Direction,
+        options: &GCodeOptions<T>,
+    ) -> String {
+        let end = rotate_around(start, self.center, direction.signed(self.angle));
+        let center = self.center - start;
+
+        format!(
+            "{} {} I{} J{} F{}",
+            match direction {
+                Direction::Left => "G3",
+                Direction::Right => "G2",
+            },
+            options.point(end),
+            options.number(center.x),
+            options.number(center.y),
+            options.number(options.feedrate)
+        )
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the move to the end of the segment
+    pub fn to_gcode(&self, options: &GCodeOptions<T>) -> String {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => circle.to_gcode(*start, *direction, options),
+            Self::Straight(straight) => straight.to_gcode(options),
+        }
+    }
+}
+
+/// get the program of the segments (only the header for no segments)
+///
+/// moves ending where the previous one ended (after rounding) are left out,
+/// controllers would cut such an arc as a full circle, only circles turning
+/// more than half a turn are kept as they are (almost) full circles
+fn program<T>(segments: &[Segment<T>], options: &GCodeOptions<T>) -> Result<String, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    if options.feedrate.is_nan() || options.feedrate <= T::zero() {
+        return Err(Error::InvalidFeedrate);
+    }
+
+    let mut lines = vec![options.units.code().to_string(), "G90".into(), "G17".into()];
+    let mut position = match segments.first() {
+        Some(first) => options.point(first.pose_at(T::zero()).point),
+        None => return Ok(lines.join("\n") + "\n"),
+    };
+    lines.push(format!("G0 {}", position));
+
+    for segment in segments.iter() {
+        let end = options.point(segment.pose_at(segment.get_length()).point);
+        let full_circle = match segment {
+            Segment::Circle { circle, .. } => circle.angle.radians > T::PI(),
+            Segment::Straight(_) => false,
+        };
+
+        if end != position || full_circle {
+            lines.push(segment.to_gcode(options));
+            position = end;
+        }
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the G-code program driving the path
+    ///
+    /// returns an error if the feedrate is not positive
+    pub fn to_gcode(&self, options: &GCodeOptions<T>) -> Result<String, Error> {
+        program(&self.segments(), options)
+    }
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the G-code program driving the path
+    ///
+    /// returns an error if the feedrate is not positive
+    pub fn to_gcode(&self, options: &GCodeOptions<T>) -> Result<String, Error> {
+        program(&self.segments, options)
+    }
+}
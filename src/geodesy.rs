@@ -0,0 +1,347 @@
+//! Paths between geodetic (WGS84) poses
+//!
+//! The poses are converted into a local east north up (ENU) frame at the start
+//! position, the tangent plane of the ellipsoid there. East is the x-axis and
+//! north the y-axis, so headings clockwise from north are angles clockwise from
+//! the positive y-axis like everywhere else in this crate. The path is computed
+//! in that plane and can be converted back to geodetic points or exported as a
+//! GeoJSON feature.
+//!
+//! The plane deviates from the ellipsoid with the distance from its origin
+//! (about 8 m height after 10 km), so the paths should stay within some
+//! kilometers of their start. The north of the end position turns against the
+//! north of the plane (about 0.1° per 10 km east or west at mid latitudes), its
+//! heading is corrected for that.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{get_shortest, segment_path::SegmentPath, Path, Point, Pose};
+
+/// semi-major axis of the WGS84 ellipsoid in meters
+const SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+
+/// flattening of the WGS84 ellipsoid
+const FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// number of iterations to get the latitude from earth centered coordinates
+const LATITUDE_ITERATIONS: usize = 8;
+
+/// Position on the WGS84 ellipsoid
+#[derive(Debug, Copy, Clone)]
+pub struct Geodetic<T> {
+    pub latitude: Angle<T>,
+    pub longitude: Angle<T>,
+    /// height above the ellipsoid in meters
+    pub altitude: T,
+}
+
+/// Geodetic position with a heading (clockwise from north)
+#[derive(Debug, Copy, Clone)]
+pub struct GeoPose<T> {
+    pub position: Geodetic<T>,
+    pub heading: Angle<T>,
+}
+
+/// Local east north up frame with its origin at a geodetic position
+#[derive(Debug, Copy, Clone)]
+pub struct LocalFrame<T> {
+    pub origin: Geodetic<T>,
+}
+
+/// Path in the local frame at its start
+#[derive(Debug, Copy, Clone)]
+pub struct GeoPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    pub frame: LocalFrame<T>,
+    /// path from the origin of the frame (in meters east and north)
+    pub path: Path<T>,
+    /// minimum radius the path has been computed with
+    pub radius: T,
+}
+
+impl<T> Geodetic<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new geodetic position from degrees and meters
+    pub fn new(latitude: T, longitude: T, altitude: T) -> Self {
+        Self {
+            latitude: Angle::degrees(latitude),
+            longitude: Angle::degrees(longitude),
+            altitude,
+        }
+    }
+
+    /// get the earth centered earth fixed coordinates in meters
+    fn to_ecef(self) -> [T; 3] {
+        let semi_major_axis: T = SEMI_MAJOR_AXIS.into();
+        let eccentricity: T = (FLATTENING * (2.0 - FLATTENING)).into();
+        let (sin_latitude, cos_latitude) = (
+            Float::sin(self.latitude.radians),
+            Float::cos(self.latitude.radians),
+        );
+        let (sin_longitude, cos_longitude) = (
+            Float::sin(self.longitude.radians),
+            Float::cos(self.longitude.radians),
+        );
+
+        // radius of curvature in the prime vertical
+        let normal =
+            semi_major_axis / (T::one() - eccentricity * sin_latitude * sin_latitude).sqrt();
+        [
+            (normal + self.altitude) * cos_latitude * cos_longitude,
+            (normal + self.altitude) * cos_latitude * sin_longitude,
+            (normal * (T::one() - eccentricity) + self.altitude) * sin_latitude,
+        ]
+    }
+
+    /// get the unit vectors east, north and up at the position in earth centered coordinates
+    fn axes(&self) -> [[T; 3]; 3] {
+        let (sin_latitude, cos_latitude) = (
+            Float::sin(self.latitude.radians),
+            Float::cos(self.latitude.radians),
+        );
+        let (sin_longitude, cos_longitude) = (
+            Float::sin(self.longitude.radians),
+            Float::cos(self.longitude.radians),
+        );
+
+        [
+            [-sin_longitude, cos_longitude, T::zero()],
+            [
+                -sin_latitude * cos_longitude,
+                -sin_latitude * sin_longitude,
+                cos_latitude,
+            ],
+            [
+                cos_latitude * cos_longitude,
+                cos_latitude * sin_longitude,
+                sin_latitude,
+            ],
+        ]
+    }
+
+    /// get the position from earth centered earth fixed coordinates in meters
+    /// (not defined at the poles)
+    fn from_ecef([x, y, z]: [T; 3]) -> Self {
+        let semi_major_axis: T = SEMI_MAJOR_AXIS.into();
+        let eccentricity: T = (FLATTENING * (2.0 - FLATTENING)).into();
+        let distance = x.hypot(y);
+
+        let mut latitude = z.atan2(distance * (T::one() - eccentricity));
+        let mut altitude = T::zero();
+        for _ in 0..LATITUDE_ITERATIONS {
+            let sin_latitude = Float::sin(latitude);
+            let normal =
+                semi_major_axis / (T::one() - eccentricity * sin_latitude * sin_latitude).sqrt();
+            altitude = distance / Float::cos(latitude) - normal;
+            latitude = z.atan2(distance * (T::one() - eccentricity * normal / (normal + altitude)));
+        }
+
+        Self {
+            latitude: Angle::radians(latitude),
+            longitude: Angle::radians(y.atan2(x)),
+            altitude,
+        }
+    }
+}
+
+impl<T> GeoPose<T> {
+    /// create a new geodetic pose
+    pub fn new(position: Geodetic<T>, heading: Angle<T>) -> Self {
+        Self { position, heading }
+    }
+}
+
+impl<T> LocalFrame<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new local frame at the origin
+    pub fn new(origin: Geodetic<T>) -> Self {
+        Self { origin }
+    }
+
+    /// get the east, north and up coordinates of the position in meters
+    pub fn to_local(&self, position: &Geodetic<T>) -> [T; 3] {
+        let origin = self.origin.to_ecef();
+        let point = position.to_ecef();
+        let offset = [
+            point[0] - origin[0],
+            point[1] - origin[1],
+            point[2] - origin[2],
+        ];
+
+        let mut local = [T::zero(); 3];
+        for (coordinate, axis) in local.iter_mut().zip(self.origin.axes().iter()) {
+            *coordinate = axis[0] * offset[0] + axis[1] * offset[1] + axis[2] * offset[2];
+        }
+        local
+    }
+
+    /// get the position of the east, north and up coordinates in meters
+    pub fn to_geodetic(&self, local: [T; 3]) -> Geodetic<T> {
+        let mut point = self.origin.to_ecef();
+        for (coordinate, axis) in local.iter().zip(self.origin.axes().iter()) {
+            for (value, component) in point.iter_mut().zip(axis.iter()) {
+                *value = *value + *component * *coordinate;
+            }
+        }
+        Geodetic::from_ecef(point)
+    }
+
+    /// get the pose in the plane (east and north) of the geodetic pose
+    ///
+    /// the heading is clockwise from the north at the position, which turns
+    /// against the north of the frame away from its origin (the meridians
+    /// converge), so it is turned into a heading clockwise from the north of the frame
+    pub fn to_pose(&self, pose: &GeoPose<T>) -> Pose<T> {
+        let [east, north, _] = self.to_local(&pose.position);
+
+        let [position_east, position_north, _] = pose.position.axes();
+        let (sin, cos) = (
+            Float::sin(pose.heading.radians),
+            Float::cos(pose.heading.radians),
+        );
+        let [frame_east, frame_north, _] = self.origin.axes();
+        let (mut x, mut y) = (T::zero(), T::zero());
+        for index in 0..3 {
+            let direction = position_east[index] * sin + position_north[index] * cos;
+            x = x + direction * frame_east[index];
+            y = y + direction * frame_north[index];
+        }
+
+        Pose::new(
+            Point::new(east, north),
+            Angle::radians(x.atan2(y)).positive(),
+        )
+    }
+
+    /// get the position of the point in the plane (at the height of the plane)
+    pub fn point_to_geodetic(&self, point: Point<T>) -> Geodetic<T> {
+        self.to_geodetic([point.x, point.y, T::zero()])
+    }
+}
+
+impl<T> GeoPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get geodetic positions along the path at most the step (in meters) apart
+    /// including the start and end position
+    pub fn sample(&self, step: T) -> Vec<Geodetic<T>> {
+        SegmentPath::from(self.path)
+            .sample(step)
+            .iter()
+            .map(|pose| self.frame.point_to_geodetic(pose.point))
+            .collect()
+    }
+}
+
+impl<T> GeoPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get a GeoJSON feature with the positions along the path at most the step
+    /// (in meters) apart as a line string and the path type, the length and the
+    /// radius (in meters) as properties
+    pub fn to_geojson(&self, step: T) -> String {
+        let coordinates: Vec<String> = self
+            .sample(step)
+            .iter()
+            .map(|position| {
+                format!(
+                    "[{},{}]",
+                    position.longitude.to_degrees(),
+                    position.latitude.to_degrees()
+                )
+            })
+            .collect();
+
+        format!(
+            concat!(
+                "{{\"type\":\"Feature\",",
+                "\"geometry\":{{\"type\":\"LineString\",\"coordinates\":[{}]}},",
+                "\"properties\":{{\"path_type\":\"{:?}\",\"length\":{},\"radius\":{}}}}}"
+            ),
+            coordinates.join(","),
+            self.path.path_type(),
+            self.path.get_length(),
+            self.radius
+        )
+    }
+}
+
+/// get the shortest path with the radius (in meters) from the start to the end pose
+/// in the local frame at the start position
+pub fn get_shortest_geodetic<T>(radius: T, start: &GeoPose<T>, end: &GeoPose<T>) -> GeoPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let frame = LocalFrame::new(start.position);
+    let origin = frame.to_pose(start);
+    let end = frame.to_pose(end).relative_to(&origin);
+
+    GeoPath {
+        frame,
+        path: get_shortest(radius, end.point, end.angle).placed_at(&origin),
+        radius,
+    }
+}
@@ -0,0 +1,148 @@
+//! Intercepting a moving target
+//!
+//! The target drives straight on with a constant speed. The vehicle intercepts it
+//! at the earliest time where the shortest path to the pose of the target at that
+//! time is exactly as long as the distance the vehicle drives until then, so both
+//! arrive at the same time with the same heading.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{get_shortest, Error, Path, Pose, Vector};
+
+/// number of times tried between zero and the maximum time
+const SAMPLES: usize = 1024;
+
+/// number of bisection steps to find the exact intercept time
+const BISECTION_STEPS: usize = 128;
+
+/// Target driving straight on with a constant speed
+#[derive(Debug, Copy, Clone)]
+pub struct Target<T> {
+    /// pose of the target at time zero
+    pub pose: Pose<T>,
+    pub speed: T,
+}
+
+impl<T> Target<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new target
+    pub fn new(pose: Pose<T>, speed: T) -> Self {
+        Self { pose, speed }
+    }
+
+    /// get the pose of the target at the time
+    pub fn pose_at(&self, time: T) -> Pose<T> {
+        let angle = self.pose.angle.radians;
+        let heading = Vector::new(Float::sin(angle), Float::cos(angle));
+        Pose::new(
+            self.pose.point + heading * (self.speed * time),
+            self.pose.angle,
+        )
+    }
+}
+
+/// Path meeting the target
+#[derive(Debug, Copy, Clone)]
+pub struct Intercept<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// time until the target is met
+    pub time: T,
+    /// pose of the target (and the vehicle) at the time
+    pub pose: Pose<T>,
+    /// shortest path from (0,0) facing in positive y-direction to the pose
+    pub path: Path<T>,
+}
+
+/// get the earliest time up to the maximum time at which the vehicle driving with
+/// the speed on the shortest path with the radius meets the target
+///
+/// the time is searched on evenly spaced samples and refined by bisection, so a
+/// meeting shorter than the distance between two samples might be missed
+///
+/// if the length of the shortest path jumps at the intercept time, the path
+/// is slightly shorter than the distance driven until then
+///
+/// returns an error if the speed is not positive or if the target cannot be
+/// intercepted until the maximum time
+pub fn intercept<T>(
+    radius: T,
+    speed: T,
+    target: &Target<T>,
+    max_time: T,
+) -> Result<Intercept<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if speed.is_nan() || speed <= T::zero() {
+        return Err(Error::InvalidSpeed);
+    }
+
+    let meeting = |time: T| {
+        let pose = target.pose_at(time);
+        let path = get_shortest(radius, pose.point, pose.angle);
+        let ahead = path.get_length() <= speed * time;
+        (Intercept { time, pose, path }, ahead)
+    };
+
+    let mut previous = T::zero();
+    for index in 0..=SAMPLES {
+        let fraction: T = (index as f64 / SAMPLES as f64).into();
+        let time = max_time * fraction;
+        let (current, ahead) = meeting(time);
+        if !ahead {
+            previous = time;
+            continue;
+        }
+        if index == 0 {
+            return Ok(current);
+        }
+
+        // the vehicle is too late at the previous time and early enough at this one
+        let (mut low, mut high) = (previous, time);
+        let mut result = current;
+        for _ in 0..BISECTION_STEPS {
+            let middle = (low + high) / 2.0.into();
+            match meeting(middle) {
+                (intercept, true) => {
+                    high = middle;
+                    result = intercept;
+                }
+                (_, false) => low = middle,
+            }
+        }
+        return Ok(result);
+    }
+
+    Err(Error::NoIntercept)
+}
@@ -28,7 +28,96 @@
 //!
 //!   note: both paths can return an error if the points are too far apart
 //!
+//! If only the length of the shortest path is needed `shortest_length` computes it
+//! without constructing any of the routes, which takes about half the time
+//! (`cargo bench` compares both)
 //!
+//! Paths can be moved with a rigid `Transform` (`Path::transform`), mirrored about
+//! an axis (`Path::mirror_about_axis`) and driven backwards (`Path::reversed`)
+//!
+//! Exact axis aligned bounding boxes and conservative convex hulls of paths are
+//! computed in the [`bounds`] module
+//!
+//! Offset paths at a lateral distance (e.g. the borders of the swept area) are
+//! computed in the [`offset`] module
+//!
+//! Polylines within a tolerance of the path (with only as many points as needed
+//! on the circles) are computed in the [`polyline`] module
+//!
+//! Cubic Bezier curves within a tolerance of the path (e.g. for graphics or CAD)
+//! are computed in the [`bezier`] module
+//!
+//! Paths between latitude/longitude poses (in a local east north up frame) and
+//! their export as GeoJSON are provided by the [`geodesy`] module
+//!
+//! Geodetic paths can be flown by autopilots with the mission items and waypoint
+//! files (QGroundControl) of the [`mission`] module
+//!
+//! Tool moves along paths (`G1` lines and `G2`/`G3` arcs) for CNC machines and
+//! plotters are emitted by the [`gcode`] module
+//!
+//! Paths can be split at a distance (`Path::split_at`) and put together again with
+//! the [`segment_path`] module, e.g. to replace the rest of a path when replanning.
+//! Its `SegmentPath` holds any number of `Segment`s (circles and straights), so it
+//! also represents paths that are no Dubins routes
+//!
+//! Paths can be converted from and to the normalized representation used in the
+//! literature (path type and three segment lengths) with the [`normalized`] module
+//!
+//! To compute many paths between arbitrary start and end poses at once see the
+//! [`batch`] module (parallelized with the `rayon` feature)
+//!
+//! The closest point of a path to a given point and the cross track error there
+//! can be computed with `Path::project` (see the [`projection`] module)
+//!
+//! To follow a path with a vehicle the [`controller`] module provides pure pursuit
+//! and Stanley path tracking controllers
+//!
+//! A path can be turned into a time parameterized trajectory within speed and
+//! acceleration limits with the [`trajectory`] module
+//!
+//! Steering angles and wheel speeds to drive a path with car-like or differential
+//! drive vehicles are provided by the [`commands`] module
+//!
+//! The [`simulation`] module drives paths or controller commands with a kinematic
+//! vehicle model
+//!
+//! Paths without jumps in the curvature (clothoids between the circles and the
+//! tangent) are computed in the [`clothoid`] module
+//!
+//! Paths with an exact length (e.g. for simultaneous arrival) are computed in the
+//! [`fixed_length`] module
+//!
+//! A target moving straight on with a constant speed can be met with the
+//! [`intercept`] module
+//!
+//! Paths entering a circle (e.g. a loiter orbit) at any of its points are
+//! computed in the [`orbit`] module
+//!
+//! Paths joining a line (e.g. a lane or a runway approach), optionally with a
+//! minimum straight along it, are computed in the [`line`](mod@line) module
+//!
+
+pub mod batch;
+pub mod bezier;
+pub mod bounds;
+pub mod clothoid;
+pub mod commands;
+pub mod controller;
+pub mod fixed_length;
+pub mod gcode;
+pub mod geodesy;
+pub mod intercept;
+pub mod line;
+pub mod mission;
+pub mod normalized;
+pub mod offset;
+pub mod orbit;
+pub mod polyline;
+pub mod projection;
+pub mod segment_path;
+pub mod simulation;
+pub mod trajectory;
 
 use std::{
     cmp::PartialOrd,
@@ -38,7 +127,9 @@ use std::{
 
 use convert::From;
 pub use euclid::Angle;
-use euclid::{approxeq::ApproxEq, Point2D, Rotation2D, Trig, UnknownUnit, Vector2D};
+use euclid::{
+    approxeq::ApproxEq, Box2D, Point2D, Rotation2D, Transform2D, Trig, UnknownUnit, Vector2D,
+};
 use num_traits::{
     self,
     float::{Float, FloatConst},
@@ -48,6 +139,8 @@ use thiserror::Error;
 
 pub type Point<T> = Point2D<T, UnknownUnit>;
 pub type Vector<T> = Vector2D<T, UnknownUnit>;
+pub type Transform<T> = Transform2D<T, UnknownUnit, UnknownUnit>;
+pub type BoundingBox<T> = Box2D<T, UnknownUnit>;
 type Rotation<T> = Rotation2D<T, UnknownUnit, UnknownUnit>;
 
 #[derive(Debug, Error)]
@@ -56,6 +149,192 @@ pub enum Error {
     CirclesTooClose,
     #[error("ccc path cannot be constructed (circles too far apart)")]
     CirclesTooFarApart,
+    #[error("the number of start poses does not match the number of end poses")]
+    PoseCountMismatch,
+    #[error("the limits have to be positive")]
+    InvalidLimits,
+    #[error("the speed has to be positive")]
+    InvalidSpeed,
+    #[error("the time step has to be positive")]
+    InvalidTimeStep,
+    #[error("the length is shorter than the shortest path")]
+    LengthTooShort,
+    #[error("no path with the length has been found")]
+    LengthNotReachable,
+    #[error("the target cannot be intercepted before the maximum time")]
+    NoIntercept,
+    #[error("the tolerance has to be positive and above the floating point precision")]
+    InvalidTolerance,
+    #[error("the feedrate has to be positive")]
+    InvalidFeedrate,
+    #[error("the orbit radius has to be at least the positive turning radius")]
+    InvalidOrbit,
+}
+
+/// Point with a heading
+///
+/// the angle is measured clockwise from the positive y-axis, the same way as the end_angle
+#[derive(Debug, Copy, Clone)]
+pub struct Pose<T> {
+    pub point: Point<T>,
+    pub angle: Angle<T>,
+}
+
+impl<T> Pose<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new pose
+    pub fn new(point: Point<T>, angle: Angle<T>) -> Self {
+        Self { point, angle }
+    }
+
+    /// express this pose in the frame of the origin pose
+    /// (the origin pose becomes (0,0) facing in positive y-direction)
+    pub fn relative_to(&self, origin: &Self) -> Self {
+        // the heading is clockwise so rotating counter clockwise by it undoes it
+        Self {
+            point: Rotation::new(origin.angle)
+                .transform_point(self.point - origin.point.to_vector()),
+            angle: (self.angle - origin.angle).positive(),
+        }
+    }
+
+    /// express a pose given in the frame of the origin pose in the frame
+    /// the origin pose is given in (inverse of relative_to)
+    pub fn from_relative(&self, origin: &Self) -> Self {
+        Self {
+            point: origin.point
+                + Rotation::new(origin.angle)
+                    .inverse()
+                    .transform_vector(self.point.to_vector()),
+            angle: (self.angle + origin.angle).positive(),
+        }
+    }
+
+    /// approximate equality to other Pose
+    pub fn approx_eq(&self, other: Self) -> bool {
+        ApproxEq::approx_eq(&self.point, &other.point)
+            && (ApproxEq::approx_eq(&self.angle, &other.angle)
+                || ApproxEq::approx_eq(&self.angle.signed(), &other.angle.signed()))
+    }
+}
+
+/// exact angle of the vector from the positive x-axis
+/// (euclid's angle_from_x_axis only approximates atan2)
+fn angle_from_x_axis<T: Float>(vector: Vector<T>) -> Angle<T> {
+    Angle::radians(vector.y.atan2(vector.x))
+}
+
+/// exact counter clockwise angle from one vector to another
+/// (euclid's angle_to only approximates atan2)
+fn angle_between<T: Float>(from: Vector<T>, to: Vector<T>) -> Angle<T> {
+    Angle::radians(from.cross(to).atan2(from.dot(to)))
+}
+
+/// tolerance for deciding whether circles coincide or touch each other,
+/// large enough to cover rounding errors but small enough not to change the path noticeably
+fn tolerance<T: Float>(radius: T) -> T {
+    radius * T::epsilon().sqrt()
+}
+
+/// get the angle and magnitude of the outer tangent between two circles with the same radius
+///
+/// the tangent is parallel to the line between the circle centers, if the circles coincide
+/// there is no tangent and it gets the direction of the end angle instead
+fn outer_tangent<T: Float + FloatConst>(
+    start_center: Point<T>,
+    end_center: Point<T>,
+    radius: T,
+    end_angle: Angle<T>,
+) -> (Angle<T>, T) {
+    let center_vector = end_center - start_center;
+    let center_distance = center_vector.length();
+
+    if center_distance > tolerance(radius) {
+        (angle_from_x_axis(center_vector), center_distance)
+    } else {
+        // the end angle is clockwise from the y axis
+        (Angle::frac_pi_2() - end_angle, T::zero())
+    }
+}
+
+/// get the angle of the line between the circle centers, the angle between that line and the
+/// inner tangent between two circles with the same radius and the magnitude of the tangent
+///
+/// circles that touch each other (within the tolerance) have a tangent without length,
+/// overlapping circles have no inner tangent
+fn inner_tangent<T: Float>(
+    start_center: Point<T>,
+    end_center: Point<T>,
+    radius: T,
+) -> Result<(Angle<T>, Angle<T>, T), Error> {
+    let center_vector = end_center - start_center;
+    let center_distance = center_vector.length();
+    let diameter = radius + radius;
+
+    // check if inside tangent can even be constructed
+    if center_distance < diameter - tolerance(radius) {
+        return Err(Error::CirclesTooClose);
+    }
+
+    // get the tangent length via some simple trigonometry
+    let tangent_magnitude = (center_distance * center_distance - diameter * diameter)
+        .max(T::zero())
+        .sqrt();
+
+    // the tangent crosses the line between the circle centers in the middle
+    // under the angle whose sine is diameter / center_distance
+    Ok((
+        angle_from_x_axis(center_vector),
+        Angle::radians(diameter.atan2(tangent_magnitude)),
+        tangent_magnitude,
+    ))
+}
+
+/// angle of the vector clockwise from the positive y-axis (the way headings are given)
+fn heading_of<T: Float>(vector: Vector<T>) -> Angle<T> {
+    Angle::radians(vector.x.atan2(vector.y))
+}
+
+/// rotate the point counter clockwise around the center
+fn rotate_around<T: Float>(point: Point<T>, center: Point<T>, angle: Angle<T>) -> Point<T> {
+    center + Rotation::new(angle).transform_vector(point - center)
+}
+
+/// get the reflection about the line through the point of the axis pose in its heading
+fn reflection<T: Float>(axis: &Pose<T>) -> Transform<T> {
+    let (x, y) = (axis.angle.radians.sin(), axis.angle.radians.cos());
+    let two = T::one() + T::one();
+
+    // the component along the axis stays, the one across it is negated
+    let linear = Transform::new(
+        two * x * x - T::one(),
+        two * x * y,
+        two * x * y,
+        two * y * y - T::one(),
+        T::zero(),
+        T::zero(),
+    );
+    linear.then_translate(axis.point.to_vector() - linear.transform_vector(axis.point.to_vector()))
+}
+
+/// get the direction of a circle turning in the direction after the (rigid) transform
+/// (the opposite one for a reflecting transform)
+fn transformed<T: Float>(direction: Direction, transform: &Transform<T>) -> Direction {
+    if transform.determinant() < T::zero() {
+        direction.opposite()
+    } else {
+        direction
+    }
 }
 
 /// Vector with origin, angle and magnitude
@@ -73,6 +352,29 @@ impl<T: ApproxEq<T>> StraightPath<T> {
     }
 }
 
+impl<T: Float> StraightPath<T> {
+    /// get the straight moved by the (rigid) transform
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        Self {
+            origin: transform.transform_point(self.origin),
+            vector: transform.transform_vector(self.vector),
+        }
+    }
+
+    /// get the straight mirrored about the line through the point of the axis pose in its heading
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the straight driven from its end to its origin
+    pub fn reversed(&self) -> Self {
+        Self {
+            origin: self.origin + self.vector,
+            vector: -self.vector,
+        }
+    }
+}
+
 /// Circle vector (Circle + Angle)
 #[derive(Debug, Copy, Clone)]
 pub struct CirclePath<T>
@@ -109,6 +411,7 @@ where
         self.angle.radians * self.radius
     }
     /// approximate equality to other CirclePath
+    #[allow(clippy::if_same_then_else, clippy::needless_bool)]
     pub fn approx_eq(&self, other: Self) -> bool {
         if !ApproxEq::approx_eq(&self.center, &other.center) {
             false
@@ -124,6 +427,43 @@ where
     }
 }
 
+impl<T> CirclePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the circle moved by the (rigid) transform
+    ///
+    /// the circle has no direction, a reflecting transform turns the segment or
+    /// route it belongs to into the opposite direction
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        Self {
+            center: transform.transform_point(self.center),
+            ..*self
+        }
+    }
+
+    /// get the circle mirrored about the line through the point of the axis pose in its heading
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the circle driven from its end to its start
+    ///
+    /// the circle has no direction, so it stays the same: the direction and the
+    /// start point of the reversed drive live in the segment or route it belongs to
+    pub fn reversed(&self) -> Self {
+        *self
+    }
+}
+
 /// Route with a start Circle, a tangent straight and a end Circle
 #[derive(Debug, Copy, Clone)]
 pub struct RouteCSC<T>
@@ -142,6 +482,8 @@ where
     pub start: CirclePath<T>,
     pub tangent: StraightPath<T>,
     pub end: CirclePath<T>,
+    pub start_direction: Direction,
+    pub end_direction: Direction,
 }
 
 /// Route with 3 Circles
@@ -162,6 +504,8 @@ where
     pub start: CirclePath<T>,
     pub middle: CirclePath<T>,
     pub end: CirclePath<T>,
+    /// direction of the start and end circle (the middle circle turns the other way)
+    pub direction: Direction,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -182,6 +526,271 @@ where
     CCC(RouteCCC<T>),
 }
 
+/// Part of a path, either a circle turning in a direction or a straight
+#[derive(Debug, Copy, Clone)]
+pub enum Segment<T>
+where
+    T: Mul<T, Output = T>
+        + Mul
+        + ApproxEq<T>
+        + Rem<Output = T>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Zero
+        + FloatConst
+        + PartialOrd
+        + Copy,
+{
+    /// circle starting at the start point turning in the direction
+    Circle {
+        circle: CirclePath<T>,
+        start: Point<T>,
+        direction: Direction,
+    },
+    Straight(StraightPath<T>),
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the length of the segment
+    pub fn get_length(&self) -> T {
+        match self {
+            Self::Circle { circle, .. } => circle.get_length(),
+            Self::Straight(straight) => straight.vector.length(),
+        }
+    }
+
+    /// get the pose after driving the distance along the segment
+    pub fn pose_at(&self, distance: T) -> Pose<T> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                let turn = Angle::radians(distance / circle.radius);
+                let point = rotate_around(*start, circle.center, direction.signed(turn));
+                Pose::new(
+                    point,
+                    heading_of(circle.heading_at(point, *direction)).positive(),
+                )
+            }
+            Self::Straight(straight) => {
+                let length = straight.vector.length();
+                let point = if length > T::zero() {
+                    straight.origin + straight.vector * (distance / length)
+                } else {
+                    straight.origin
+                };
+                Pose::new(point, heading_of(straight.vector).positive())
+            }
+        }
+    }
+
+    /// split the segment after driving the (clamped) distance along it
+    pub fn split_at(&self, distance: T) -> (Self, Self) {
+        let distance = distance.max(T::zero()).min(self.get_length());
+
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                let angle = Angle::radians(distance / circle.radius);
+                (
+                    Self::Circle {
+                        circle: CirclePath { angle, ..*circle },
+                        start: *start,
+                        direction: *direction,
+                    },
+                    Self::Circle {
+                        circle: CirclePath {
+                            angle: circle.angle - angle,
+                            ..*circle
+                        },
+                        start: rotate_around(*start, circle.center, direction.signed(angle)),
+                        direction: *direction,
+                    },
+                )
+            }
+            Self::Straight(straight) => {
+                let length = straight.vector.length();
+                let first = if length > T::zero() {
+                    straight.vector * (distance / length)
+                } else {
+                    straight.vector
+                };
+                (
+                    Self::Straight(StraightPath {
+                        origin: straight.origin,
+                        vector: first,
+                    }),
+                    Self::Straight(StraightPath {
+                        origin: straight.origin + first,
+                        vector: straight.vector - first,
+                    }),
+                )
+            }
+        }
+    }
+
+    /// get the segment moved by the (rigid) transform
+    ///
+    /// a reflecting transform turns a circle into the opposite direction
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => Self::Circle {
+                circle: circle.transform(transform),
+                start: transform.transform_point(*start),
+                direction: transformed(*direction, transform),
+            },
+            Self::Straight(straight) => Self::Straight(straight.transform(transform)),
+        }
+    }
+
+    /// get the segment mirrored about the line through the point of the axis pose in its heading
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the segment driven from its end to its start
+    pub fn reversed(&self) -> Self {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => Self::Circle {
+                circle: circle.reversed(),
+                start: rotate_around(*start, circle.center, direction.signed(circle.angle)),
+                direction: direction.opposite(),
+            },
+            Self::Straight(straight) => Self::Straight(straight.reversed()),
+        }
+    }
+
+    /// get the curvature of the segment (positive when turning left)
+    pub fn curvature(&self) -> T {
+        match self {
+            Self::Circle {
+                circle, direction, ..
+            } => {
+                direction
+                    .signed(Angle::radians(circle.radius.recip()))
+                    .radians
+            }
+            Self::Straight(_) => T::zero(),
+        }
+    }
+}
+
+/// Turning direction of a circle
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// get the other direction
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// get the counter clockwise angle of a turn with the given magnitude in this direction
+    pub(crate) fn signed<T: Float>(self, angle: Angle<T>) -> Angle<T> {
+        match self {
+            Self::Left => angle,
+            Self::Right => Angle::radians(-angle.radians),
+        }
+    }
+}
+
+/// Type ("word") of a path, the turning directions of its circles
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PathType {
+    RSR,
+    RSL,
+    LSR,
+    LSL,
+    RLR,
+    LRL,
+}
+
+impl PathType {
+    /// all path types
+    pub const ALL: [Self; 6] = [
+        Self::RSR,
+        Self::RSL,
+        Self::LSR,
+        Self::LSL,
+        Self::RLR,
+        Self::LRL,
+    ];
+
+    /// is this a circle straight circle path type
+    pub fn is_csc(self) -> bool {
+        !self.is_ccc()
+    }
+
+    /// is this a circle circle circle path type
+    pub fn is_ccc(self) -> bool {
+        matches!(self, Self::RLR | Self::LRL)
+    }
+
+    /// direction of the start circle
+    pub fn start_direction(self) -> Direction {
+        match self {
+            Self::RSR | Self::RSL | Self::RLR => Direction::Right,
+            Self::LSR | Self::LSL | Self::LRL => Direction::Left,
+        }
+    }
+
+    /// direction of the end circle
+    pub fn end_direction(self) -> Direction {
+        match self {
+            Self::RSR | Self::LSR | Self::RLR => Direction::Right,
+            Self::RSL | Self::LSL | Self::LRL => Direction::Left,
+        }
+    }
+
+    /// get the circle straight circle path type with the given circle directions
+    pub fn csc(start: Direction, end: Direction) -> Self {
+        match (start, end) {
+            (Direction::Right, Direction::Right) => Self::RSR,
+            (Direction::Right, Direction::Left) => Self::RSL,
+            (Direction::Left, Direction::Right) => Self::LSR,
+            (Direction::Left, Direction::Left) => Self::LSL,
+        }
+    }
+
+    /// get the circle circle circle path type with the given outer circle direction
+    pub fn ccc(start: Direction) -> Self {
+        match start {
+            Direction::Right => Self::RLR,
+            Direction::Left => Self::LRL,
+        }
+    }
+}
+
 /// Route with a start Circle, a tangent straight and a end Circle
 impl<T> RouteCSC<T>
 where
@@ -210,22 +819,10 @@ where
 
         // get the tangent pitch which is the same as the pitch between the two
         // circle centers since our circles have the same radius
-        let mut tangent_angle = Angle::radians(
-            ((end_center.y - start_center.y) / (end_center.x - start_center.x)).atan(),
-        );
-
-        // if the end circle center x value is smaller than the
-        // start circle center x value
-        // the angle would be rotated by π so to prevent that:
-        if end_center.x < start_center.x {
-            tangent_angle = tangent_angle + Angle::pi();
-        }
-
-        // get the tangent magnitude this, again, is the same as the distance
-        // between the two circle centers since our circles have the same radius
-        let tangent_magnitude = ((end_center.x - start_center.x).powi(2)
-            + (end_center.y - start_center.y).powi(2))
-        .sqrt();
+        // and the tangent magnitude this, again, is the same as the distance
+        // between the two circle centers
+        let (tangent_angle, tangent_magnitude) =
+            outer_tangent(start_center, end_center, radius, end_angle);
 
         // get the angle of the start circle
         let start_angle = (Angle::frac_pi_2() - tangent_angle).positive();
@@ -256,6 +853,8 @@ where
                 radius,
                 angle: end_angle,
             },
+            start_direction: Direction::Right,
+            end_direction: Direction::Right,
         })
     }
 
@@ -271,25 +870,9 @@ where
             + Rotation::new(Angle::pi() - end_angle)
                 .transform_vector(Vector::new(radius, 0.0.into()));
 
-        // get the tangent pitch which is the same as the pitch between the two
-        // circle centers since our circles have the same radius
-        let mut tangent_angle = Angle::radians(
-            ((end_center.y - start_center.y) / (end_center.x - start_center.x)).atan(),
-        )
-        .positive();
-
-        // if the end circle center x value is smaller than the
-        // start circle center x value
-        // the angle would be π rotated so to prevent that:
-        if end_center.x < start_center.x {
-            tangent_angle = (tangent_angle + Angle::pi()).positive();
-        }
-
-        // get the tangent magnitude this, again, is the same as the distance
-        // between the two circle centers since our circles have the same radius
-        let tangent_magnitude = ((end_center.x - start_center.x).abs().powi(2)
-            + (end_center.y - start_center.y).abs().powi(2))
-        .sqrt();
+        // get the tangent pitch and magnitude (see rsr)
+        let (tangent_angle, tangent_magnitude) =
+            outer_tangent(start_center, end_center, radius, end_angle);
 
         // get the angle of the start circle
         let start_angle = (tangent_angle - Angle::frac_pi_2()).positive();
@@ -299,10 +882,10 @@ where
         let tangent_origin = start_center
             + Rotation::new(start_angle).transform_vector(Vector::new(radius, 0.0.into()));
 
-        // get the angle of the start circle
-        // the angle where we start from the tangent equals the one we finish
-        // so we can use that in here
-        let end_angle = (end_angle - start_angle).positive();
+        // get the angle of the end circle
+        // the start circle turned counter clockwise by the start angle and the end
+        // circle keeps turning counter clockwise until it reaches the end angle
+        let end_angle = (-start_angle - end_angle).positive();
 
         Ok(Self {
             start: CirclePath {
@@ -319,6 +902,8 @@ where
                 radius,
                 angle: end_angle,
             },
+            start_direction: Direction::Left,
+            end_direction: Direction::Left,
         })
     }
 
@@ -334,35 +919,11 @@ where
             + Rotation::new(Angle::pi() - end_angle)
                 .transform_vector(Vector::new(radius, 0.0.into()));
 
-        // check if inside tangent can even be constructed
-        if ((end_center.x - start_center.x).powi(2) + (end_center.y - start_center.y).powi(2))
-            .sqrt()
-            < radius * 2.0
-        {
-            return Err(Error::CirclesTooClose);
-        }
-
-        // get the tangent length via some simple trigonometry
-        let tangent_magnitude = ((end_center.x - start_center.x).powi(2)
-            + (end_center.y - start_center.y).powi(2)
-            - (radius * 2.0).powi(2))
-        .sqrt();
-
-        // tangent middle is the same as the middle of the straight from the center of the start
-        let tangent_middle = end_center.lerp(start_center, 0.5.into());
-
-        // get the tangent angle
-        let mut tangent_angle = Angle::radians(
-            ((end_center.y - tangent_middle.y) / (end_center.x - tangent_middle.x)).atan()
-                - (radius * 2.0 / tangent_magnitude).atan(),
-        );
+        let (center_angle, tangent_angle, tangent_magnitude) =
+            inner_tangent(start_center, end_center, radius)?;
 
-        // if the end circle center x value is smaller than the
-        // start circle center x value
-        // the angle would be π rotated so to prevent that:
-        if end_center.x < start_center.x {
-            tangent_angle = tangent_angle + Angle::pi();
-        }
+        // the tangent is rotated clockwise against the line between the circle centers
+        let tangent_angle = center_angle - tangent_angle;
 
         // get the angle of the start circle
         let start_angle = (Angle::frac_pi_2() - tangent_angle).positive();
@@ -391,6 +952,8 @@ where
                 radius,
                 angle: end_angle,
             },
+            start_direction: Direction::Right,
+            end_direction: Direction::Left,
         })
     }
 
@@ -407,35 +970,11 @@ where
                 .inverse()
                 .transform_vector(Vector::new(radius, 0.0.into()));
 
-        // check if inside tangent can even be constructed
-        if ((end_center.x - start_center.x).powi(2) + (end_center.y - start_center.y).powi(2))
-            .sqrt()
-            < radius * 2.0
-        {
-            return Err(Error::CirclesTooClose);
-        }
-
-        // get the tangent length via some simple trigonometry
-        let tangent_magnitude = ((end_center.x - start_center.x).powi(2)
-            + (end_center.y - start_center.y).powi(2)
-            - (radius * 2.0).powi(2))
-        .sqrt();
+        let (center_angle, tangent_angle, tangent_magnitude) =
+            inner_tangent(start_center, end_center, radius)?;
 
-        // tangent middle is the same as the middle of the straight from the center of the start
-        let tangent_middle = end_center.lerp(start_center, 0.5.into());
-
-        // get the tangent angle
-        let mut tangent_angle = Angle::radians(
-            ((end_center.y - tangent_middle.y) / (end_center.x - tangent_middle.x)).atan()
-                + (radius * 2.0 / tangent_magnitude).atan(),
-        );
-
-        // if the end circle center x value is smaller than the
-        // start circle center x value
-        // the angle would rotated by π so to prevent that:
-        if end_center.x < start_center.x {
-            tangent_angle = tangent_angle + Angle::pi();
-        }
+        // the tangent is rotated counter clockwise against the line between the circle centers
+        let tangent_angle = center_angle + tangent_angle;
 
         // get the angle of the start circle
         let start_angle = (tangent_angle - Angle::frac_pi_2()).positive();
@@ -446,7 +985,8 @@ where
             + Rotation::new(start_angle).transform_vector(Vector::new(radius, 0.0.into()));
 
         // get the angle of the end circle
-        let end_angle = ((Angle::frac_pi_2() - end_angle) - tangent_angle).positive();
+        // the end circle turns clockwise from the tangent heading to the end angle
+        let end_angle = (end_angle - (Angle::frac_pi_2() - tangent_angle)).positive();
 
         Ok(Self {
             start: CirclePath {
@@ -463,6 +1003,8 @@ where
                 radius,
                 angle: end_angle,
             },
+            start_direction: Direction::Left,
+            end_direction: Direction::Right,
         })
     }
 
@@ -471,6 +1013,11 @@ where
         self.start.get_length() + self.tangent.vector.length() + self.end.get_length()
     }
 
+    /// get the path type
+    pub fn path_type(&self) -> PathType {
+        PathType::csc(self.start_direction, self.end_direction)
+    }
+
     /// get the shortest circle straight circle route
     pub fn get_shortest(
         radius: T,
@@ -501,6 +1048,37 @@ where
 
         Ok(route_csc)
     }
+
+    /// get the route moved by the (rigid) transform
+    ///
+    /// a reflecting transform turns the circles into the opposite direction
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        Self {
+            start: self.start.transform(transform),
+            tangent: self.tangent.transform(transform),
+            end: self.end.transform(transform),
+            start_direction: transformed(self.start_direction, transform),
+            end_direction: transformed(self.end_direction, transform),
+        }
+    }
+
+    /// get the route mirrored about the line through the point of the axis pose in its heading
+    /// (e.g. a rsl route mirrored about the start pose becomes a lsr route)
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the route driven from its end to its start
+    /// (e.g. a rsl route becomes a rsl route from the end pose facing backwards)
+    pub fn reversed(&self) -> Self {
+        Self {
+            start: self.end,
+            tangent: self.tangent.reversed(),
+            end: self.start,
+            start_direction: self.end_direction.opposite(),
+            end_direction: self.start_direction.opposite(),
+        }
+    }
 }
 
 /// Route with 3 Circles
@@ -516,7 +1094,7 @@ where
         + ApproxEq<T>
         + Trig,
 {
-    /// right left right route (not working yet)
+    /// right left right route
     pub fn rlr(radius: T, end_point: Point<T>, end_angle: Angle<T>) -> Result<Self, Error> {
         let start_center = Point::new(radius, 0.0.into());
 
@@ -530,10 +1108,7 @@ where
                 .transform_vector(Vector::new(radius, 0.0.into()));
 
         // check if path can be constructed or if the circles are too far apart
-        if ((end_center.x - start_center.x).powi(2) + (end_center.y - start_center.y).powi(2))
-            .sqrt()
-            > (radius * 4.0)
-        {
+        if (end_center - start_center).length() > radius * 4.0 + tolerance(radius) {
             return Err(Error::CirclesTooFarApart);
         }
 
@@ -543,9 +1118,14 @@ where
             let vector_start_center_end_center =
                 Vector::new(end_center.x - start_center.x, end_center.y - start_center.y);
 
+            // the middle circle has to lie on the right side of the line between the
+            // outer circle centers, only then the middle arc is longer than π which is
+            // required for the path to be a candidate for the shortest path
             let vector_start_center_middle_center_angle =
-                vector_start_center_end_center.angle_from_x_axis().radians
-                    + (vector_start_center_end_center.length() / (radius * 4.0)).acos();
+                angle_from_x_axis(vector_start_center_end_center).radians
+                    - (vector_start_center_end_center.length() / (radius * 4.0))
+                        .min(T::one())
+                        .acos();
 
             vector_start_center_middle_center = Vector::new(
                 (radius * 2.0) * Trig::cos(vector_start_center_middle_center_angle),
@@ -564,20 +1144,19 @@ where
         );
 
         let start_angle =
-            (Angle::pi() - vector_start_center_middle_center.angle_from_x_axis()).positive();
+            (Angle::pi() - angle_from_x_axis(vector_start_center_middle_center)).positive();
 
-        let middle_angle = Rotation::new(Angle::pi())
-            .transform_vector(vector_start_center_middle_center)
-            .angle_to(vector_middle_center_end_center)
-            .positive();
+        let middle_angle = angle_between(
+            Rotation::new(Angle::pi()).transform_vector(vector_start_center_middle_center),
+            vector_middle_center_end_center,
+        )
+        .positive();
 
-        let end_angle = Rotation::new(Angle::pi())
-            .transform_vector(vector_middle_center_end_center)
-            .angle_to(Vector::new(
-                end_point.x - end_center.x,
-                end_point.y - end_center.y,
-            ))
-            .positive();
+        let end_angle = angle_between(
+            Vector::new(end_point.x - end_center.x, end_point.y - end_center.y),
+            Rotation::new(Angle::pi()).transform_vector(vector_middle_center_end_center),
+        )
+        .positive();
 
         Ok(Self {
             start: CirclePath {
@@ -595,10 +1174,11 @@ where
                 radius,
                 angle: end_angle,
             },
+            direction: Direction::Right,
         })
     }
 
-    /// left right left route (not working yet)
+    /// left right left route
     pub fn lrl(radius: T, end_point: Point<T>, end_angle: Angle<T>) -> Result<Self, Error> {
         let start_center = Point::new(-radius, 0.0.into());
 
@@ -611,10 +1191,7 @@ where
                 .transform_vector(Vector::new(radius, 0.0.into()));
 
         // check if path can be constructed or if the circles are too far apart
-        if ((end_center.x - start_center.x).powi(2) + (end_center.y - start_center.y).powi(2))
-            .sqrt()
-            > (radius * 4.0)
-        {
+        if (end_center - start_center).length() > radius * 4.0 + tolerance(radius) {
             return Err(Error::CirclesTooFarApart);
         }
 
@@ -624,9 +1201,13 @@ where
             let vector_start_center_end_center =
                 Vector::new(end_center.x - start_center.x, end_center.y - start_center.y);
 
+            // the middle circle has to lie on the left side of the line between the
+            // outer circle centers (see rlr)
             let vector_start_center_middle_center_angle =
-                vector_start_center_end_center.angle_from_x_axis().radians
-                    - (vector_start_center_end_center.length() / (radius * 4.0)).acos();
+                angle_from_x_axis(vector_start_center_end_center).radians
+                    + (vector_start_center_end_center.length() / (radius * 4.0))
+                        .min(T::one())
+                        .acos();
 
             vector_start_center_middle_center = Vector::new(
                 (radius * 2.0) * Trig::cos(vector_start_center_middle_center_angle),
@@ -644,17 +1225,19 @@ where
             end_center.y - middle_center.y,
         );
 
-        let start_angle = (vector_start_center_middle_center.angle_from_x_axis()).positive();
+        let start_angle = angle_from_x_axis(vector_start_center_middle_center).positive();
 
-        let middle_angle = vector_middle_center_end_center
-            .angle_to(
-                Rotation::new(Angle::pi()).transform_vector(vector_start_center_middle_center),
-            )
-            .positive();
+        let middle_angle = angle_between(
+            vector_middle_center_end_center,
+            Rotation::new(Angle::pi()).transform_vector(vector_start_center_middle_center),
+        )
+        .positive();
 
-        let end_angle = Vector::new(end_point.x - end_center.x, end_point.y - end_center.y)
-            .angle_to(Rotation::new(Angle::pi()).transform_vector(vector_middle_center_end_center))
-            .positive();
+        let end_angle = angle_between(
+            Rotation::new(Angle::pi()).transform_vector(vector_middle_center_end_center),
+            Vector::new(end_point.x - end_center.x, end_point.y - end_center.y),
+        )
+        .positive();
 
         Ok(Self {
             start: CirclePath {
@@ -672,6 +1255,7 @@ where
                 radius,
                 angle: end_angle,
             },
+            direction: Direction::Left,
         })
     }
 
@@ -680,6 +1264,11 @@ where
         self.start.get_length() + self.middle.get_length() + self.end.get_length()
     }
 
+    /// get the path type
+    pub fn path_type(&self) -> PathType {
+        PathType::ccc(self.direction)
+    }
+
     /// get the shortest circle circle circle route
     pub fn get_shortest(
         radius: T,
@@ -705,6 +1294,191 @@ where
             Err(Error::CirclesTooFarApart)
         }
     }
+
+    /// get the route moved by the (rigid) transform
+    ///
+    /// a reflecting transform turns the circles into the opposite direction
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        Self {
+            start: self.start.transform(transform),
+            middle: self.middle.transform(transform),
+            end: self.end.transform(transform),
+            direction: transformed(self.direction, transform),
+        }
+    }
+
+    /// get the route mirrored about the line through the point of the axis pose in its heading
+    /// (e.g. a rlr route mirrored about the start pose becomes a lrl route)
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the route driven from its end to its start
+    /// (the directions of all circles become the opposite, so a rlr route becomes a lrl route)
+    pub fn reversed(&self) -> Self {
+        Self {
+            start: self.end,
+            middle: self.middle,
+            end: self.start,
+            direction: self.direction.opposite(),
+        }
+    }
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the length of the path
+    pub fn get_length(&self) -> T {
+        match self {
+            Self::CSC(route_csc) => route_csc.get_length(),
+            Self::CCC(route_ccc) => route_ccc.get_length(),
+        }
+    }
+
+    /// get the path type
+    pub fn path_type(&self) -> PathType {
+        match self {
+            Self::CSC(route_csc) => route_csc.path_type(),
+            Self::CCC(route_ccc) => route_ccc.path_type(),
+        }
+    }
+
+    /// get the segments of the path in driving order
+    pub fn segments(&self) -> [Segment<T>; 3] {
+        let path_type = self.path_type();
+        let start_direction = path_type.start_direction();
+        let end_direction = path_type.end_direction();
+
+        // the start point is where the start circle ends turned back by its angle
+        let start_of = |circle: CirclePath<T>, end: Point<T>| {
+            rotate_around(end, circle.center, -start_direction.signed(circle.angle))
+        };
+
+        match self {
+            Self::CSC(route_csc) => [
+                Segment::Circle {
+                    circle: route_csc.start,
+                    start: start_of(route_csc.start, route_csc.tangent.origin),
+                    direction: start_direction,
+                },
+                Segment::Straight(route_csc.tangent),
+                Segment::Circle {
+                    circle: route_csc.end,
+                    start: route_csc.tangent.origin + route_csc.tangent.vector,
+                    direction: end_direction,
+                },
+            ],
+            Self::CCC(route_ccc) => {
+                // the circles touch each other in the middle between their centers
+                let first_contact = route_ccc
+                    .start
+                    .center
+                    .lerp(route_ccc.middle.center, 0.5.into());
+                let second_contact = route_ccc
+                    .middle
+                    .center
+                    .lerp(route_ccc.end.center, 0.5.into());
+
+                [
+                    Segment::Circle {
+                        circle: route_ccc.start,
+                        start: start_of(route_ccc.start, first_contact),
+                        direction: start_direction,
+                    },
+                    Segment::Circle {
+                        circle: route_ccc.middle,
+                        start: first_contact,
+                        direction: start_direction.opposite(),
+                    },
+                    Segment::Circle {
+                        circle: route_ccc.end,
+                        start: second_contact,
+                        direction: end_direction,
+                    },
+                ]
+            }
+        }
+    }
+
+    /// get the segment the distance along the path lies on
+    /// and the distance from the start of that segment
+    ///
+    /// the distance is clamped to the path
+    fn segment_at(&self, distance: T) -> (Segment<T>, T) {
+        let segments = self.segments();
+        let mut distance = distance.max(T::zero());
+
+        for segment in segments[..2].iter() {
+            if distance <= segment.get_length() {
+                return (*segment, distance);
+            }
+            distance = distance - segment.get_length();
+        }
+
+        (segments[2], distance.min(segments[2].get_length()))
+    }
+
+    /// get the pose after driving the distance along the path
+    ///
+    /// the distance is clamped to the path, so the start and end pose are
+    /// returned for distances before and after the path
+    pub fn pose_at(&self, distance: T) -> Pose<T> {
+        let (segment, distance) = self.segment_at(distance);
+        segment.pose_at(distance)
+    }
+
+    /// get the curvature after driving the distance along the path
+    /// (the inverse of the radius, positive when turning left and zero on the tangent)
+    pub fn curvature_at(&self, distance: T) -> T {
+        self.segment_at(distance).0.curvature()
+    }
+
+    /// move a path given in the frame of the origin pose into the frame
+    /// the origin pose is given in
+    pub(crate) fn placed_at(&self, origin: &Pose<T>) -> Self {
+        self.transform(
+            &Rotation::new(origin.angle)
+                .inverse()
+                .to_transform()
+                .then_translate(origin.point.to_vector()),
+        )
+    }
+
+    /// get the path moved by the (rigid) transform
+    ///
+    /// a reflecting transform turns every circle into the opposite direction
+    ///
+    /// only rotations, translations and reflections are supported: the radius
+    /// stays the same, so a scaled or sheared path is no valid path anymore
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        match self {
+            Self::CSC(route_csc) => Self::CSC(route_csc.transform(transform)),
+            Self::CCC(route_ccc) => Self::CCC(route_ccc.transform(transform)),
+        }
+    }
+
+    /// get the path mirrored about the line through the point of the axis pose in its heading
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        self.transform(&reflection(axis))
+    }
+
+    /// get the path driven from its end to its start (facing the other way)
+    pub fn reversed(&self) -> Self {
+        match self {
+            Self::CSC(route_csc) => Self::CSC(route_csc.reversed()),
+            Self::CCC(route_ccc) => Self::CCC(route_ccc.reversed()),
+        }
+    }
 }
 
 /// get the shortest path
@@ -732,3 +1506,104 @@ where
         Path::CSC(route_csc)
     }
 }
+
+/// get the length of the shortest path without constructing it
+///
+/// this only evaluates the segment lengths of the normalized formulation
+/// instead of constructing the routes like
+/// `get_shortest(radius, end_point, end_angle).get_length()`, the circles of
+/// a route are skipped if its straight or middle circle alone is already longer
+/// than the shortest route so far
+pub fn shortest_length<T>(radius: T, end_point: Point<T>, end_angle: Angle<T>) -> T
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let (d, alpha, beta) = normalized::normalize(radius, end_point, end_angle);
+
+    normalized::shortest_length(d, alpha, beta) * radius
+}
+
+/// get the parameter from the minimum to the maximum with the shortest length
+///
+/// evenly spaced parameters are tried and each of them shorter than its
+/// neighbours is narrowed down between them, so a shorter length between two
+/// other parameters might be missed (the narrowed parameter can be up to one
+/// spacing outside of the range)
+///
+/// the length may jump, e.g. where a route becomes infeasible, the narrowing
+/// then ends at the shorter side of the jump
+pub(crate) fn search_shortest<T, F>(minimum: T, maximum: T, length: F) -> T
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+    F: Fn(T) -> T,
+{
+    /// number of parameters tried
+    const SAMPLES: usize = 256;
+    /// number of steps to narrow down a parameter
+    const SEARCH_STEPS: usize = 128;
+
+    let step: T = (1.0 / SAMPLES as f64).into();
+    let step = step * (maximum - minimum);
+    let shorter = |best: (T, T), parameter: T| {
+        let current = (parameter, length(parameter));
+        if current.1 < best.1 {
+            current
+        } else {
+            best
+        }
+    };
+
+    let samples: Vec<(T, T)> = (0..=SAMPLES)
+        .map(|index| {
+            let index: T = (index as f64).into();
+            let parameter = minimum + step * index;
+            (parameter, length(parameter))
+        })
+        .collect();
+
+    let mut best = (minimum, T::infinity());
+    for (index, &(parameter, sample)) in samples.iter().enumerate() {
+        let neighbour = |index: Option<usize>| {
+            index
+                .and_then(|index| samples.get(index))
+                .map_or(T::infinity(), |neighbour| neighbour.1)
+        };
+        if neighbour(index.checked_sub(1)) < sample || neighbour(Some(index + 1)) < sample {
+            continue;
+        }
+
+        // ternary search between the neighbours of the parameter
+        let (mut low, mut high) = (parameter - step, parameter + step);
+        for _ in 0..SEARCH_STEPS {
+            let third = (high - low) / 3.0.into();
+            if length(low + third) < length(high - third) {
+                high = high - third;
+            } else {
+                low = low + third;
+            }
+        }
+
+        // the length might not be unimodal between the neighbours
+        // or jump between the narrowed bounds
+        best = [parameter, low, high]
+            .iter()
+            .fold(best, |best, parameter| shorter(best, *parameter));
+    }
+    best.0
+}
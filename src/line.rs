@@ -0,0 +1,162 @@
+//! Paths onto a line (e.g. a lane or a runway approach)
+//!
+//! The path joins the line at any of its points heading in the direction of the
+//! line. Optionally the point of the line is the target and the path has to
+//! drive a minimum distance straight along the line before it reaches it, e.g.
+//! to be settled on the line before a runway threshold.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{get_shortest, search_shortest, Path, Point, Pose, StraightPath, Vector};
+
+/// Line through the point heading in the direction of the angle
+#[derive(Debug, Copy, Clone)]
+pub struct Line<T> {
+    pub point: Point<T>,
+    /// heading along the line, measured clockwise from the positive y-axis like the end_angle
+    pub angle: Angle<T>,
+}
+
+impl<T> Line<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new line
+    pub fn new(point: Point<T>, angle: Angle<T>) -> Self {
+        Self { point, angle }
+    }
+
+    /// get the unit vector along the line
+    fn direction(&self) -> Vector<T> {
+        let angle = self.angle.radians;
+        Vector::new(Float::sin(angle), Float::cos(angle))
+    }
+
+    /// get the pose on the line at the (signed) distance from its point heading along it
+    pub fn pose_at(&self, distance: T) -> Pose<T> {
+        Pose::new(self.point + self.direction() * distance, self.angle)
+    }
+
+    /// get the (signed) distance along the line to the point closest to the given point
+    pub fn distance_to(&self, point: Point<T>) -> T {
+        (point - self.point).dot(self.direction())
+    }
+}
+
+/// Path onto the line followed by the straight along the line
+#[derive(Debug, Copy, Clone)]
+pub struct LinePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// path from (0,0) facing in positive y-direction onto the line
+    pub path: Path<T>,
+    /// straight along the line from the end of the path to the point of the line
+    /// (without a length if there is no straight in distance)
+    pub straight: StraightPath<T>,
+}
+
+impl<T> LinePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the total length of the path and the straight
+    pub fn get_length(&self) -> T {
+        self.path.get_length() + self.straight.vector.length()
+    }
+
+    /// get the pose at the end of the straight
+    pub fn end(&self) -> Pose<T> {
+        let end = self.path.pose_at(self.path.get_length());
+        Pose::new(self.straight.origin + self.straight.vector, end.angle)
+    }
+}
+
+/// get the shortest path from (0,0) facing in positive y-direction onto the line,
+/// heading along the line
+///
+/// without a straight in distance the path joins the line at any of its points.
+/// With a straight in distance the path joins the line at least this distance
+/// before the point of the line and drives straight along it up to the point
+/// (a negative distance counts as zero)
+///
+/// the joining point is found by searching, so the path is only as short as the
+/// search is accurate (far below any practical tolerance)
+pub fn path_to_line<T>(radius: T, line: &Line<T>, straight_in: Option<T>) -> LinePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let onto = |distance: T| {
+        let end = line.pose_at(distance);
+        get_shortest(radius, end.point, end.angle).get_length()
+    };
+
+    let distance = match straight_in {
+        // no path is shorter than the distance to its end, so the path to the closest
+        // point of the line bounds how far away from it the best point can be
+        None => {
+            let closest = line.distance_to(Point::new(T::zero(), T::zero()));
+            let bound = onto(closest);
+            search_shortest(closest - bound, closest + bound, onto)
+        }
+        // the straight alone is as long as the distance before the point, so the length
+        // joining at the latest point bounds how far before the point the best one can be
+        Some(straight_in) => {
+            let latest = -straight_in.max(T::zero());
+            let with_straight = |distance: T| {
+                let distance = distance.min(latest);
+                onto(distance) - distance
+            };
+            let bound = with_straight(latest);
+            search_shortest(-bound, latest, with_straight).min(latest)
+        }
+    };
+
+    let end = line.pose_at(distance);
+    let straight = match straight_in {
+        Some(_) => line.direction() * -distance,
+        None => Vector::new(T::zero(), T::zero()),
+    };
+    LinePath {
+        path: get_shortest(radius, end.point, end.angle),
+        straight: StraightPath {
+            origin: end.point,
+            vector: straight,
+        },
+    }
+}
@@ -0,0 +1,273 @@
+//! Mission waypoint files for autopilots
+//!
+//! Paths in the local frame of the [`geodesy`](crate::geodesy) module are
+//! converted into MAVLink mission items and written as QGroundControl waypoint
+//! files (`QGC WPL 110`), which ArduPilot, PX4 and QGroundControl read.
+//!
+//! Straights end at a waypoint (`MAV_CMD_NAV_WAYPOINT`). A circle followed by a
+//! straight can be flown as a loiter (`MAV_CMD_NAV_LOITER_TURNS`) around its
+//! center with its radius, direction and fraction of a turn. The loiters are
+//! meant for ArduPilot, which leaves them once the vehicle heads towards the
+//! next waypoint (the heading required flag), close to the end of the circle
+//! but depending on its navigation controller; other autopilots may leave at
+//! the end of the turns. Every other circle (or every circle if loiters are
+//! not wanted) is replaced by waypoints along it within the tolerance.
+//!
+//! The home position is written with its altitude above mean sea level, the
+//! ellipsoidal height of the local frame minus the geoid height of the options.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    geodesy::{GeoPath, Geodetic, LocalFrame},
+    segment_path::SegmentPath,
+    Direction, Error, Segment,
+};
+
+/// MAVLink command to fly to a waypoint
+const MAV_CMD_NAV_WAYPOINT: u16 = 16;
+
+/// MAVLink command to loiter around a center for a number of turns
+const MAV_CMD_NAV_LOITER_TURNS: u16 = 18;
+
+/// MAVLink frame of positions with the altitude above mean sea level (home position)
+const MAV_FRAME_GLOBAL: u8 = 0;
+
+/// MAVLink frame of positions with the altitude above the home position
+const MAV_FRAME_GLOBAL_RELATIVE_ALT: u8 = 3;
+
+/// Mission item of a path
+#[derive(Debug, Copy, Clone)]
+pub enum MissionItem<T> {
+    /// fly straight to the position
+    Waypoint(Geodetic<T>),
+    /// loiter around the center for the (fractional) number of turns
+    /// and leave towards the next waypoint
+    LoiterTurns {
+        center: Geodetic<T>,
+        radius: T,
+        turns: T,
+        direction: Direction,
+    },
+}
+
+/// Options for converting a path into mission items
+#[derive(Debug, Copy, Clone)]
+pub struct MissionOptions<T> {
+    /// largest deviation of the waypoints replacing circles from the path in meters
+    pub tolerance: T,
+    /// altitude of the items above the home position in meters
+    pub altitude: T,
+    /// height of the geoid (mean sea level) above the ellipsoid at the home
+    /// position in meters, e.g. from EGM96
+    pub geoid_height: T,
+    /// fly circles followed by a straight as loiters instead of waypoints
+    pub loiter_turns: bool,
+}
+
+/// Mission items of a path with the start as home position
+#[derive(Debug, Clone)]
+pub struct Mission<T> {
+    pub home: Geodetic<T>,
+    pub items: Vec<MissionItem<T>>,
+    /// altitude of the items above the home position in meters
+    pub altitude: T,
+    /// height of the geoid (mean sea level) above the ellipsoid at the home position in meters
+    pub geoid_height: T,
+}
+
+impl<T> MissionOptions<T> {
+    /// create new mission options
+    pub fn new(tolerance: T, altitude: T, geoid_height: T, loiter_turns: bool) -> Self {
+        Self {
+            tolerance,
+            altitude,
+            geoid_height,
+            loiter_turns,
+        }
+    }
+}
+
+impl<T> Mission<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the mission items of the path in the local frame starting with a
+    /// waypoint at the start of the path, segments without length are left out
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn new(
+        frame: &LocalFrame<T>,
+        path: &SegmentPath<T>,
+        options: &MissionOptions<T>,
+    ) -> Result<Self, Error> {
+        if options.tolerance.is_nan() || options.tolerance <= T::zero() {
+            return Err(Error::InvalidTolerance);
+        }
+
+        let segments: Vec<&Segment<T>> = path
+            .segments
+            .iter()
+            .filter(|segment| segment.get_length() > T::zero())
+            .collect();
+        let mut items = Vec::new();
+
+        if let Some(first) = path.segments.first() {
+            let start = first.pose_at(T::zero()).point;
+            items.push(MissionItem::Waypoint(frame.point_to_geodetic(start)));
+        }
+
+        for (index, segment) in segments.iter().enumerate() {
+            match segment {
+                Segment::Circle {
+                    circle, direction, ..
+                } if options.loiter_turns
+                    && matches!(segments.get(index + 1), Some(Segment::Straight(_))) =>
+                {
+                    items.push(MissionItem::LoiterTurns {
+                        center: frame.point_to_geodetic(circle.center),
+                        radius: circle.radius,
+                        turns: circle.angle.radians / (T::PI() * 2.0),
+                        direction: *direction,
+                    })
+                }
+                Segment::Circle { .. } => items.extend(
+                    segment.to_polyline(options.tolerance)?[1..]
+                        .iter()
+                        .map(|point| MissionItem::Waypoint(frame.point_to_geodetic(*point))),
+                ),
+                Segment::Straight(straight) => items.push(MissionItem::Waypoint(
+                    frame.point_to_geodetic(straight.origin + straight.vector),
+                )),
+            }
+        }
+
+        Ok(Self {
+            home: frame.origin,
+            items,
+            altitude: options.altitude,
+            geoid_height: options.geoid_height,
+        })
+    }
+}
+
+impl<T> Mission<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig
+        + Display,
+{
+    /// get the QGroundControl waypoint file (`QGC WPL 110`) of the mission
+    ///
+    /// the home position is the first line with its altitude above mean sea level,
+    /// the items follow with the altitude of the mission above it
+    pub fn to_waypoint_file(&self) -> String {
+        let line =
+            |index: usize, frame: u8, command: u16, params: [T; 4], position: &Geodetic<T>| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t1\n",
+                    index,
+                    if index == 0 { 1 } else { 0 },
+                    frame,
+                    command,
+                    params[0],
+                    params[1],
+                    params[2],
+                    params[3],
+                    position.latitude.to_degrees(),
+                    position.longitude.to_degrees(),
+                    if index == 0 {
+                        position.altitude - self.geoid_height
+                    } else {
+                        self.altitude
+                    }
+                )
+            };
+
+        let mut file = String::from("QGC WPL 110\n");
+        file.push_str(&line(
+            0,
+            MAV_FRAME_GLOBAL,
+            MAV_CMD_NAV_WAYPOINT,
+            [T::zero(); 4],
+            &self.home,
+        ));
+
+        for (index, item) in self.items.iter().enumerate() {
+            file.push_str(&match item {
+                MissionItem::Waypoint(position) => line(
+                    index + 1,
+                    MAV_FRAME_GLOBAL_RELATIVE_ALT,
+                    MAV_CMD_NAV_WAYPOINT,
+                    [T::zero(); 4],
+                    position,
+                ),
+                // the radius is negative for counter clockwise loiters and the vehicle
+                // leaves when heading towards the next waypoint
+                MissionItem::LoiterTurns {
+                    center,
+                    radius,
+                    turns,
+                    direction,
+                } => line(
+                    index + 1,
+                    MAV_FRAME_GLOBAL_RELATIVE_ALT,
+                    MAV_CMD_NAV_LOITER_TURNS,
+                    [
+                        *turns,
+                        T::one(),
+                        match direction {
+                            Direction::Left => -*radius,
+                            Direction::Right => *radius,
+                        },
+                        T::zero(),
+                    ],
+                    center,
+                ),
+            });
+        }
+
+        file
+    }
+}
+
+impl<T> GeoPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the mission items of the path
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_mission(&self, options: &MissionOptions<T>) -> Result<Mission<T>, Error> {
+        Mission::new(&self.frame, &SegmentPath::from(self.path), options)
+    }
+}
@@ -0,0 +1,452 @@
+//! Normalized (d, alpha, beta) formulation of the Dubins paths
+//!
+//! This is the formulation used in most of the literature: the start is at the
+//! origin facing in positive x-direction, angles are counter clockwise and all
+//! distances are divided by the radius. Every word then yields the three segment
+//! lengths (t, p, q) directly without constructing any geometry.
+//!
+//! A path can be converted into its path type and normalized segment lengths
+//! with `Path::to_normalized` and rebuilt at any start pose with `Path::from_normalized`.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    rotate_around, CirclePath, Direction, Path, PathType, Point, Pose, RouteCCC, RouteCSC,
+    StraightPath, Vector,
+};
+
+/// Path type with the three segment lengths divided by the radius
+///
+/// for circles the segment length is the angle of the circle in radians
+#[derive(Debug, Copy, Clone)]
+pub struct NormalizedPath<T> {
+    pub path_type: PathType,
+    pub lengths: [T; 3],
+}
+
+impl<T: Float> NormalizedPath<T> {
+    /// get the length of the path with the given radius
+    pub fn get_length(&self, radius: T) -> T {
+        (self.lengths[0] + self.lengths[1] + self.lengths[2]) * radius
+    }
+}
+
+/// tolerance for the rounding errors of the terms of the formulas
+/// (they grow with the square of the distance)
+fn tolerance<T: Float>(d: T) -> T {
+    let eight = T::from(8).unwrap();
+    T::epsilon() * eight * (T::one() + d) * (T::one() + d)
+}
+
+/// square root that treats small negative values caused by rounding as zero
+fn rounded_sqrt<T: Float>(value: T, d: T) -> Option<T> {
+    if value < -tolerance(d) {
+        None
+    } else {
+        Some(value.max(T::zero()).sqrt())
+    }
+}
+
+/// arc cosine that treats values slightly outside of [-1, 1] caused by rounding as ±1
+fn rounded_acos<T: Float>(value: T, d: T) -> Option<T> {
+    if value.abs() > T::one() + tolerance(d) {
+        None
+    } else {
+        Some(value.max(-T::one()).min(T::one()).acos())
+    }
+}
+
+/// wrap an angle in radians into [0, 2π)
+///
+/// angles that only miss 2π by rounding are wrapped to 0 so that
+/// they don't turn into a full circle
+fn mod2pi<T: Float + FloatConst>(angle: T) -> T {
+    let tau = T::PI() + T::PI();
+    let wrapped = angle - tau * (angle / tau).floor();
+    if tau - wrapped < T::epsilon() * tau * T::from(16).unwrap() {
+        T::zero()
+    } else {
+        wrapped
+    }
+}
+
+/// get the normalized distance d and the angles alpha and beta of the start and
+/// end heading relative to the line between start and end point
+pub fn normalize<T>(radius: T, end_point: Point<T>, end_angle: Angle<T>) -> (T, T, T)
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    // our start faces in positive y-direction and the end angle is clockwise,
+    // so x in the normalized frame is our y and y is our negative x
+    let dx = end_point.y;
+    let dy = -end_point.x;
+
+    let d = (dx * dx + dy * dy).sqrt() / radius;
+    let theta = if d > T::zero() {
+        mod2pi(dy.atan2(dx))
+    } else {
+        T::zero()
+    };
+
+    (d, mod2pi(-theta), mod2pi(-end_angle.radians - theta))
+}
+
+/// sines and cosines of the angles shared by the formulas of all path types
+struct Terms<T> {
+    d: T,
+    alpha: T,
+    beta: T,
+    sa: T,
+    ca: T,
+    sb: T,
+    cb: T,
+    /// cosine of alpha - beta
+    cab: T,
+}
+
+impl<T: Float> Terms<T> {
+    fn new(d: T, alpha: T, beta: T) -> Self {
+        let (sa, ca) = alpha.sin_cos();
+        let (sb, cb) = beta.sin_cos();
+        Self {
+            d,
+            alpha,
+            beta,
+            sa,
+            ca,
+            sb,
+            cb,
+            cab: ca * cb + sa * sb,
+        }
+    }
+}
+
+/// get the normalized segment lengths of the path type
+/// or None if the path type cannot be constructed
+pub fn segment_lengths<T: Float + FloatConst>(
+    path_type: PathType,
+    d: T,
+    alpha: T,
+    beta: T,
+) -> Option<[T; 3]> {
+    lengths_within(path_type, &Terms::new(d, alpha, beta), T::infinity())
+}
+
+/// get the normalized length of the shortest path
+///
+/// the sines and cosines are only computed once for all path types and the
+/// circles of a path type are skipped once its middle segment alone is longer
+/// than the shortest path so far
+pub(crate) fn shortest_length<T: Float + FloatConst>(d: T, alpha: T, beta: T) -> T {
+    let terms = Terms::new(d, alpha, beta);
+
+    // the csc paths first, they are the shortest ones for most poses
+    [
+        PathType::LSL,
+        PathType::RSR,
+        PathType::LSR,
+        PathType::RSL,
+        PathType::RLR,
+        PathType::LRL,
+    ]
+    .iter()
+    .fold(T::infinity(), |shortest, &path_type| {
+        match lengths_within(path_type, &terms, shortest) {
+            Some([t, p, q]) => shortest.min(t + p + q),
+            None => shortest,
+        }
+    })
+}
+
+/// get the normalized segment lengths of the path type or None if the path
+/// type cannot be constructed or its middle segment is not shorter than the bound
+fn lengths_within<T: Float + FloatConst>(
+    path_type: PathType,
+    terms: &Terms<T>,
+    bound: T,
+) -> Option<[T; 3]> {
+    match path_type {
+        PathType::RSR => rsr(terms, bound),
+        PathType::RSL => rsl(terms, bound),
+        PathType::LSR => lsr(terms, bound),
+        PathType::LSL => lsl(terms, bound),
+        PathType::RLR => rlr(terms, bound),
+        PathType::LRL => lrl(terms, bound),
+    }
+}
+
+/// the middle segment if it is shorter than the bound
+fn within<T: Float>(middle: T, bound: T) -> Option<T> {
+    if middle < bound {
+        Some(middle)
+    } else {
+        None
+    }
+}
+
+/// left straight left segment lengths
+fn lsl<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        ..
+    } = *terms;
+
+    // the straight is as long as the vector between the circle centers,
+    // summing its squares doesn't cancel for (almost) coinciding circles
+    let (x, y) = (d + sa - sb, cb - ca);
+    let p = within(rounded_sqrt(x * x + y * y, d)?, bound)?;
+    // without a tangent the circles coincide and the tangent heading is undefined,
+    // so the whole turn happens on the start circle
+    let tangent = if p > tolerance(d).sqrt() {
+        y.atan2(x)
+    } else {
+        beta
+    };
+
+    Some([mod2pi(tangent - alpha), p, mod2pi(beta - tangent)])
+}
+
+/// right straight right segment lengths
+fn rsr<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        ..
+    } = *terms;
+
+    // see lsl
+    let (x, y) = (d - sa + sb, ca - cb);
+    let p = within(rounded_sqrt(x * x + y * y, d)?, bound)?;
+    let tangent = if p > tolerance(d).sqrt() {
+        y.atan2(x)
+    } else {
+        beta
+    };
+
+    Some([mod2pi(alpha - tangent), p, mod2pi(tangent - beta)])
+}
+
+/// left straight right segment lengths
+fn lsr<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        cab,
+    } = *terms;
+    let two = T::one() + T::one();
+
+    let p_squared = -two + d * d + two * cab + two * d * (sa + sb);
+    let p = within(rounded_sqrt(p_squared, d)?, bound)?;
+    let tangent = (-ca - cb).atan2(d + sa + sb) - (-two).atan2(p);
+
+    Some([mod2pi(tangent - alpha), p, mod2pi(tangent - beta)])
+}
+
+/// right straight left segment lengths
+fn rsl<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        cab,
+    } = *terms;
+    let two = T::one() + T::one();
+
+    let p_squared = -two + d * d + two * cab - two * d * (sa + sb);
+    let p = within(rounded_sqrt(p_squared, d)?, bound)?;
+    let tangent = (ca + cb).atan2(d - sa - sb) - two.atan2(p);
+
+    Some([mod2pi(alpha - tangent), p, mod2pi(beta - tangent)])
+}
+
+/// right left right segment lengths
+fn rlr<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        cab,
+    } = *terms;
+    let two = T::one() + T::one();
+    let eight = two * two * two;
+    let tau = T::PI() + T::PI();
+
+    let cos_p = (eight - two - d * d + two * cab + two * d * (sa - sb)) / eight;
+    let p = within(mod2pi(tau - rounded_acos(cos_p, d)?), bound)?;
+    let phi = (ca - cb).atan2(d - sa + sb);
+    let t = mod2pi(alpha - phi + p / two);
+
+    Some([t, p, mod2pi(alpha - beta - t + p)])
+}
+
+/// left right left segment lengths
+fn lrl<T: Float + FloatConst>(terms: &Terms<T>, bound: T) -> Option<[T; 3]> {
+    let Terms {
+        d,
+        alpha,
+        beta,
+        sa,
+        ca,
+        sb,
+        cb,
+        cab,
+    } = *terms;
+    let two = T::one() + T::one();
+    let eight = two * two * two;
+    let tau = T::PI() + T::PI();
+
+    let cos_p = (eight - two - d * d + two * cab + two * d * (sb - sa)) / eight;
+    let p = within(mod2pi(tau - rounded_acos(cos_p, d)?), bound)?;
+    let phi = (ca - cb).atan2(d + sa - sb);
+    let t = mod2pi(-alpha - phi + p / two);
+
+    Some([t, p, mod2pi(beta - alpha - t + p)])
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the path type and the segment lengths divided by the radius
+    pub fn to_normalized(&self) -> NormalizedPath<T> {
+        let lengths = match self {
+            Self::CSC(route_csc) => [
+                route_csc.start.angle.radians,
+                route_csc.tangent.vector.length() / route_csc.start.radius,
+                route_csc.end.angle.radians,
+            ],
+            Self::CCC(route_ccc) => [
+                route_ccc.start.angle.radians,
+                route_ccc.middle.angle.radians,
+                route_ccc.end.angle.radians,
+            ],
+        };
+
+        NormalizedPath {
+            path_type: self.path_type(),
+            lengths,
+        }
+    }
+
+    /// build the path from its path type and normalized segment lengths starting at the start pose
+    pub fn from_normalized(normalized: NormalizedPath<T>, start: Pose<T>, radius: T) -> Self {
+        let [t, p, q] = normalized.lengths;
+        let start_direction = normalized.path_type.start_direction();
+
+        // build the path starting at (0,0) facing in positive y-direction
+        let start_center = match start_direction {
+            Direction::Right => Point::new(radius, 0.0.into()),
+            Direction::Left => Point::new(-radius, 0.0.into()),
+        };
+        let start_circle = CirclePath {
+            center: start_center,
+            radius,
+            angle: Angle::radians(t),
+        };
+        let first_contact = rotate_around(
+            Point::new(0.0.into(), 0.0.into()),
+            start_center,
+            start_direction.signed(start_circle.angle),
+        );
+
+        let path = if normalized.path_type.is_csc() {
+            let end_direction = normalized.path_type.end_direction();
+
+            // the tangent angle from the positive x-axis is π/2 plus the counter clockwise turn
+            let tangent_angle = Angle::frac_pi_2() + start_direction.signed(start_circle.angle);
+            let tangent = StraightPath {
+                origin: first_contact,
+                vector: Vector::from_angle_and_length(tangent_angle, p * radius),
+            };
+
+            // the end circle center lies on the side of the tangent the end circle turns to
+            let end_center = tangent.origin
+                + tangent.vector
+                + Vector::from_angle_and_length(
+                    tangent_angle + end_direction.signed(Angle::frac_pi_2()),
+                    radius,
+                );
+
+            Self::CSC(RouteCSC {
+                start: start_circle,
+                tangent,
+                end: CirclePath {
+                    center: end_center,
+                    radius,
+                    angle: Angle::radians(q),
+                },
+                start_direction,
+                end_direction,
+            })
+        } else {
+            // the circles touch each other in the contact points
+            let middle_center = start_center.lerp(first_contact, 2.0.into());
+            let second_contact = rotate_around(
+                first_contact,
+                middle_center,
+                start_direction.opposite().signed(Angle::radians(p)),
+            );
+            let end_center = middle_center.lerp(second_contact, 2.0.into());
+
+            Self::CCC(RouteCCC {
+                start: start_circle,
+                middle: CirclePath {
+                    center: middle_center,
+                    radius,
+                    angle: Angle::radians(p),
+                },
+                end: CirclePath {
+                    center: end_center,
+                    radius,
+                    angle: Angle::radians(q),
+                },
+                direction: start_direction,
+            })
+        };
+
+        path.placed_at(&start)
+    }
+}
@@ -0,0 +1,144 @@
+//! Offset (parallel) paths
+//!
+//! The offset path keeps a constant lateral distance to the path, e.g. the
+//! borders of the area swept by an implement or the borders of a lane. Like the
+//! cross track error the distance is positive to the right and negative to the
+//! left of the path.
+//!
+//! Straights are moved sideways and circles get a larger or smaller radius around
+//! the same center. If the distance is larger than the radius on the inner side
+//! of a circle, the offset radius would be negative: the offset points lie on the
+//! other side of the center and run backwards, so the offset path has cusps
+//! there. Such a circle gets the absolute offset radius and starts at the offset
+//! point on the other side, circles with an offset radius of zero shrink to their
+//! center and are left out.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    segment_path::SegmentPath, tolerance, CirclePath, Direction, Path, Segment, StraightPath,
+    Vector,
+};
+
+impl<T> StraightPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the straight moved sideways by the distance (positive to the right)
+    ///
+    /// a straight without length has no sides and stays where it is
+    pub fn offset(&self, distance: T) -> Self {
+        let length = self.vector.length();
+        if length <= T::zero() {
+            return *self;
+        }
+
+        // the right side is clockwise from the driving direction
+        let right = Vector::new(self.vector.y, -self.vector.x) / length;
+        Self {
+            origin: self.origin + right * distance,
+            vector: self.vector,
+        }
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the segment at the lateral distance (positive to the right)
+    ///
+    /// none is returned if a circle shrinks to its center
+    pub fn offset(&self, distance: T) -> Option<Self> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                // the center of a left turn lies on the left side
+                let radius = match direction {
+                    Direction::Left => circle.radius + distance,
+                    Direction::Right => circle.radius - distance,
+                };
+                if radius.abs() <= tolerance(circle.radius) {
+                    return None;
+                }
+
+                // a negative radius mirrors the start point through the center
+                Some(Self::Circle {
+                    circle: CirclePath {
+                        radius: radius.abs(),
+                        ..*circle
+                    },
+                    start: circle.center + (*start - circle.center) * (radius / circle.radius),
+                    direction: *direction,
+                })
+            }
+            Self::Straight(straight) => Some(Self::Straight(straight.offset(distance))),
+        }
+    }
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the path at the lateral distance (positive to the right)
+    pub fn offset(&self, distance: T) -> Self {
+        Self::new(
+            self.segments
+                .iter()
+                .filter_map(|segment| segment.offset(distance))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the path at the lateral distance (positive to the right)
+    ///
+    /// the offset path is no Dubins route anymore (its circles have different
+    /// radii and might run backwards), so it is a segment path
+    pub fn offset(&self, distance: T) -> SegmentPath<T> {
+        SegmentPath::from(*self).offset(distance)
+    }
+}
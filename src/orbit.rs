@@ -0,0 +1,325 @@
+//! Paths onto a circle (e.g. entering a loiter orbit)
+//!
+//! The path ends at any point of the orbit, heading along the orbit in its
+//! direction, so the vehicle can keep circling from there on.
+//!
+//! Driving straight onto a tangent of the orbit is usually not the shortest
+//! way, turning onto the orbit with the (smaller) minimum radius is shorter.
+//! The last circle of the path touches the orbit, so its center lies on a
+//! circle around the orbit center. The shortest path ends where the length of
+//! its route stops changing along that circle (a root of a polynomial in the
+//! cosine of the angle at the orbit center), where the route becomes infeasible
+//! (its circles touch or are as far apart as possible) or where one of its
+//! circles vanishes (e.g. its straight is a tangent of the orbit). The shortest
+//! paths to these points are compared.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    angle_from_x_axis, get_shortest, heading_of, Direction, Error, Path, Point, Pose, Vector,
+};
+
+/// Circle to enter in the direction
+#[derive(Debug, Copy, Clone)]
+pub struct Orbit<T> {
+    pub center: Point<T>,
+    pub radius: T,
+    pub direction: Direction,
+}
+
+impl<T> Orbit<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new orbit
+    pub fn new(center: Point<T>, radius: T, direction: Direction) -> Self {
+        Self {
+            center,
+            radius,
+            direction,
+        }
+    }
+
+    /// get the pose on the orbit at the angle (counter clockwise from the positive x-axis
+    /// seen from the center) heading along the orbit
+    pub fn pose_at(&self, angle: Angle<T>) -> Pose<T> {
+        let (sin, cos) = (Float::sin(angle.radians), Float::cos(angle.radians));
+        let radial = Vector::new(cos, sin);
+
+        // a left turn heads counter clockwise around the center
+        let heading = match self.direction {
+            Direction::Left => Vector::new(-sin, cos),
+            Direction::Right => Vector::new(sin, -cos),
+        };
+
+        Pose::new(
+            self.center + radial * self.radius,
+            heading_of(heading).positive(),
+        )
+    }
+}
+
+/// get the shortest path from (0,0) facing in positive y-direction onto the orbit,
+/// ending at any point of it heading along the orbit in its direction
+///
+/// returns an error if the radius is not positive or the orbit is tighter than it
+pub fn path_to_orbit<T>(radius: T, orbit: &Orbit<T>) -> Result<Path<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if radius.is_nan() || radius <= T::zero() || orbit.radius.is_nan() || orbit.radius < radius {
+        return Err(Error::InvalidOrbit);
+    }
+
+    let path_to = |angle: T| {
+        let end = orbit.pose_at(Angle::radians(angle));
+        get_shortest(radius, end.point, end.angle)
+    };
+    Ok(entry_angles(radius, orbit).into_iter().map(path_to).fold(
+        path_to(T::zero()),
+        |shortest, path| {
+            if path.get_length() < shortest.get_length() {
+                path
+            } else {
+                shortest
+            }
+        },
+    ))
+}
+
+/// get the angles of the orbit points (counter clockwise from the positive x-axis seen
+/// from its center) where the shortest path onto the orbit can end
+///
+/// the last circle of the path touches the orbit, so its center lies on a circle around
+/// the orbit center: inside it for the direction of the orbit, outside it for the other one
+fn entry_angles<T>(radius: T, orbit: &Orbit<T>) -> Vec<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let sign = |direction: Direction| direction.signed(Angle::radians(T::one())).radians;
+    let two: T = 2.0.into();
+    let mut angles = Vec::new();
+
+    for &start_direction in [Direction::Left, Direction::Right].iter() {
+        let start_sign = sign(start_direction);
+        let start_center = Point::new(-start_sign * radius, T::zero());
+        let to_orbit = orbit.center - start_center;
+        let (distance, direction) = (to_orbit.length(), angle_from_x_axis(to_orbit).radians);
+
+        // the straight of a csc route is tangent to the orbit (its last circle vanishes)
+        let offset = sign(orbit.direction) * orbit.radius - start_sign * radius;
+        if distance > T::zero() && offset.abs() <= distance {
+            let normal = Float::acos(offset / distance);
+            let behind = if orbit.direction == Direction::Left {
+                T::PI()
+            } else {
+                T::zero()
+            };
+            angles.extend([direction + normal + behind, direction - normal + behind].iter());
+        }
+
+        // the middle circle of a ccc route touches the orbit (its last circle vanishes)
+        let middle = if start_direction == orbit.direction {
+            orbit.radius + radius
+        } else {
+            orbit.radius - radius
+        };
+        angles.extend(intersections(
+            orbit.center,
+            middle,
+            start_center,
+            two * radius,
+        ));
+
+        for &end_direction in [orbit.direction, orbit.direction.opposite()].iter() {
+            let end_sign = sign(end_direction);
+            let centers = if end_direction == orbit.direction {
+                orbit.radius - radius
+            } else {
+                orbit.radius + radius
+            };
+            if centers <= T::zero() {
+                continue;
+            }
+
+            // the end circle touches the start circle (inner tangents vanish),
+            // the circles of a ccc route are as far apart as possible
+            // or the start circle vanishes and a ccc route starts with its middle circle
+            for &(from, apart) in [
+                (start_center, two * radius),
+                (start_center, two * two * radius),
+                (Point::new(start_sign * radius, T::zero()), two * radius),
+            ]
+            .iter()
+            {
+                angles.extend(intersections(orbit.center, centers, from, apart));
+            }
+
+            // the straight of a csc route starts at the start pose (its first circle vanishes)
+            let cos = (-end_sign * radius - orbit.center.x) / centers;
+            if cos.abs() <= T::one() {
+                angles.extend([Float::acos(cos), -Float::acos(cos)].iter());
+            }
+
+            // moving the end along the orbit by an angle moves the end circle along its
+            // circle and turns it by the angle, so the length changes by the part of the
+            // movement along the straight plus the turn of the end circle, which vanishes
+            // for the cosine c of the angle between the orbit center seen from the start
+            // circle and the end circle center seen from the orbit center
+            let (d, r) = (distance, radius);
+            let mut cosines = Vec::new();
+            if end_direction == start_direction {
+                // the straight heads from the start to the end circle center
+                cosines.extend(polynomial_roots(
+                    T::zero(),
+                    centers * centers * d * d,
+                    two * r * r * centers * d,
+                    r * r * (d * d + centers * centers) - centers * centers * d * d,
+                ));
+
+                // the tangent points of a ccc route lie on a line parallel to the outer
+                // circle centers, the length changes along it by the circle distance
+                // over the height of the middle circle above that line
+                let a = d * d + centers * centers;
+                let b = two * d * centers;
+                let four = two * two;
+                cosines.extend(polynomial_roots(
+                    T::zero(),
+                    -(two + T::one()) * centers * centers * d * d,
+                    a * b / two - four * r * r * b,
+                    four * centers * centers * d * d - four * r * r * a + a * a / four,
+                ));
+            } else {
+                // the inner tangent is rotated away from the line between the centers
+                let a = d * d + centers * centers - two * two * r * r;
+                let b = two * d * centers;
+                let k = r * (d * d - centers * centers) / centers;
+                cosines.extend(polynomial_roots(
+                    -b * d * d,
+                    -a * d * d,
+                    b * d * d,
+                    a * d * d - k * k,
+                ));
+            }
+            for cosine in cosines {
+                if cosine.abs() <= T::one() {
+                    let angle = Float::acos(cosine);
+                    angles.extend([direction - angle, direction + angle].iter());
+                }
+            }
+        }
+    }
+
+    angles
+}
+
+/// get the angles (counter clockwise from the positive x-axis) of the points of the circle
+/// that are the distance apart from the point
+fn intersections<T: Float>(center: Point<T>, radius: T, point: Point<T>, distance: T) -> Vec<T> {
+    let vector = point - center;
+    let length = vector.length();
+    if radius <= T::zero() || length <= T::zero() {
+        return Vec::new();
+    }
+
+    let cos =
+        (radius * radius + length * length - distance * distance) / ((radius + radius) * length);
+    if cos.abs() > T::one() {
+        return Vec::new();
+    }
+    let direction = angle_from_x_axis(vector).radians;
+    vec![direction + cos.acos(), direction - cos.acos()]
+}
+
+/// get the real roots of a x³ + b x² + c x + d, leading coefficients may be zero
+fn polynomial_roots<T>(a: T, b: T, c: T, d: T) -> Vec<T>
+where
+    T: Float + FloatConst + From<f64>,
+{
+    let two: T = 2.0.into();
+    let three: T = 3.0.into();
+
+    let roots = if a != T::zero() {
+        // depressed cubic y³ + p y + q with x = y - b / 3a
+        let (b, c, d) = (b / a, c / a, d / a);
+        let shift = b / three;
+        let p = c - b * b / three;
+        let q = two * b * b * b / (three * three * three) - b * c / three + d;
+        let discriminant = q * q / (two * two) + p * p * p / (three * three * three);
+
+        if discriminant > T::zero() {
+            let root = discriminant.sqrt();
+            vec![(-q / two + root).cbrt() + (-q / two - root).cbrt() - shift]
+        } else if p == T::zero() {
+            vec![-shift]
+        } else {
+            // three real roots on the circle with the radius m
+            let m = two * (-p / three).sqrt();
+            let angle = (three * q / (p * m)).max(-T::one()).min(T::one()).acos() / three;
+            (0..3)
+                .map(|index| {
+                    let index: T = (index as f64).into();
+                    m * (angle - two * T::PI() * index / three).cos() - shift
+                })
+                .collect()
+        }
+    } else if b != T::zero() {
+        let discriminant = c * c - two * two * b * d;
+        if discriminant < T::zero() {
+            Vec::new()
+        } else {
+            // avoid the cancellation of the smaller root
+            let q = -(c + c.signum() * discriminant.sqrt()) / two;
+            if q == T::zero() {
+                vec![T::zero()]
+            } else {
+                vec![q / b, d / q]
+            }
+        }
+    } else if c != T::zero() {
+        vec![-d / c]
+    } else {
+        Vec::new()
+    };
+
+    // polish the roots against rounding
+    roots
+        .into_iter()
+        .map(|mut x| {
+            for _ in 0..2 {
+                let slope = (three * a * x + two * b) * x + c;
+                if slope != T::zero() {
+                    x = x - (((a * x + b) * x + c) * x + d) / slope;
+                }
+            }
+            x
+        })
+        .collect()
+}
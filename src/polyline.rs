@@ -0,0 +1,149 @@
+//! Polylines approximating paths
+//!
+//! Straights need only their two end points, circles are divided into pieces
+//! small enough that the chords deviate at most the tolerance from the circle:
+//! a chord over the angle φ deviates r (1 - cos(φ / 2)) = 2 r sin²(φ / 4) in its
+//! middle.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{rotate_around, segment_path::SegmentPath, Error, Path, Point, Segment};
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the points of a polyline from the start to the end of the segment
+    /// deviating at most the tolerance from it
+    ///
+    /// returns an error if the tolerance is not positive or (for a circle) smaller
+    /// than the precision of the floating point numbers relative to the radius
+    pub fn to_polyline(&self, tolerance: T) -> Result<Vec<Point<T>>, Error> {
+        if tolerance.is_nan() || tolerance <= T::zero() {
+            return Err(Error::InvalidTolerance);
+        }
+
+        Ok(match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => {
+                // smaller deviations are lost in the rounding of the points
+                if tolerance < circle.radius * T::epsilon() {
+                    return Err(Error::InvalidTolerance);
+                }
+
+                // the largest angle whose chord stays within the tolerance
+                // (the sine form doesn't round to zero for small tolerances)
+                let two: T = 2.0.into();
+                let max_angle =
+                    Float::asin(Float::sqrt(tolerance / (two * circle.radius)).min(T::one())) * 4.0;
+                let pieces = (circle.angle.radians / max_angle)
+                    .ceil()
+                    .to_usize()
+                    .ok_or(Error::InvalidTolerance)?
+                    .max(1);
+                let piece_count: T = (pieces as f64).into();
+
+                (0..=pieces)
+                    .map(|index| {
+                        let fraction: T = (index as f64).into();
+                        let angle = Angle::radians(circle.angle.radians * fraction / piece_count);
+                        rotate_around(*start, circle.center, direction.signed(angle))
+                    })
+                    .collect()
+            }
+            Self::Straight(straight) => vec![straight.origin, straight.origin + straight.vector],
+        })
+    }
+}
+
+/// join the polylines of the segments, leaving out segments without length
+/// and the start points shared with the previous segment
+///
+/// a path without length gets only its start point
+fn join<T>(segments: &[Segment<T>], tolerance: T) -> Result<Vec<Point<T>>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if tolerance.is_nan() || tolerance <= T::zero() {
+        return Err(Error::InvalidTolerance);
+    }
+    let mut points: Vec<Point<T>> = Vec::new();
+
+    for segment in segments.iter() {
+        if segment.get_length() <= T::zero() {
+            continue;
+        }
+        let polyline = segment.to_polyline(tolerance)?;
+        let shared = if points.is_empty() { 0 } else { 1 };
+        points.extend_from_slice(&polyline[shared..]);
+    }
+
+    if let (true, Some(first)) = (points.is_empty(), segments.first()) {
+        points.push(first.pose_at(T::zero()).point);
+    }
+    Ok(points)
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the points of a polyline from the start to the end of the path
+    /// deviating at most the tolerance from it
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_polyline(&self, tolerance: T) -> Result<Vec<Point<T>>, Error> {
+        join(&self.segments(), tolerance)
+    }
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the points of a polyline from the start to the end of the path
+    /// deviating at most the tolerance from it (empty for a path without segments)
+    ///
+    /// returns an error if the tolerance is not positive
+    pub fn to_polyline(&self, tolerance: T) -> Result<Vec<Point<T>>, Error> {
+        join(&self.segments, tolerance)
+    }
+}
@@ -0,0 +1,215 @@
+//! Closest points on paths
+//!
+//! Projecting a point (e.g. the current vehicle position) onto a path gives the
+//! closest point of the path, how far along the path it lies, the heading there
+//! and the signed lateral distance (cross track error) to the path.
+//!
+//! The cross track error is positive if the point lies on the right side of the
+//! path and negative if it lies on the left side.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{
+    angle_between, heading_of, rotate_around, CirclePath, Direction, Path, Point, Segment,
+    StraightPath, Vector,
+};
+
+/// Closest point on a path or segment
+#[derive(Debug, Copy, Clone)]
+pub struct Projection<T> {
+    /// the closest point
+    pub point: Point<T>,
+    /// the length along the path or segment up to the closest point
+    pub distance: T,
+    /// the heading at the closest point (clockwise from the positive y-axis)
+    pub angle: Angle<T>,
+    /// the signed distance to the closest point (positive on the right side)
+    pub cross_track_error: T,
+}
+
+impl<T> Projection<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create the projection of the point onto the closest point with the given heading
+    fn new(point: Point<T>, closest: Point<T>, distance: T, heading: Vector<T>) -> Self {
+        let offset = point - closest;
+
+        // the cross product is positive if the offset points to the left of the heading
+        let cross_track_error = if heading.cross(offset) > T::zero() {
+            -offset.length()
+        } else {
+            offset.length()
+        };
+
+        Self {
+            point: closest,
+            distance,
+            angle: heading_of(heading),
+            cross_track_error,
+        }
+    }
+}
+
+impl<T> StraightPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the closest point of the straight to the point
+    pub fn project(&self, point: Point<T>) -> Projection<T> {
+        let length = self.vector.length();
+
+        // distance along the straight clamped to its ends
+        let distance = if length > T::zero() {
+            ((point - self.origin).dot(self.vector) / length)
+                .max(T::zero())
+                .min(length)
+        } else {
+            T::zero()
+        };
+
+        let closest = if length > T::zero() {
+            self.origin + self.vector * (distance / length)
+        } else {
+            self.origin
+        };
+
+        Projection::new(point, closest, distance, self.vector)
+    }
+}
+
+impl<T> CirclePath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the heading vector at a point of the circle when turning in the direction
+    pub(crate) fn heading_at(&self, point: Point<T>, direction: Direction) -> Vector<T> {
+        let radial = point - self.center;
+        match direction {
+            Direction::Left => Vector::new(-radial.y, radial.x),
+            Direction::Right => Vector::new(radial.y, -radial.x),
+        }
+    }
+
+    /// get the closest point of the circle to the point
+    /// if the circle starts at the start point and turns in the direction
+    pub fn project(&self, start: Point<T>, direction: Direction, point: Point<T>) -> Projection<T> {
+        let radial = point - self.center;
+        let end = rotate_around(start, self.center, direction.signed(self.angle));
+
+        // angle the circle has to turn from the start to reach the point
+        let turn = direction
+            .signed(angle_between(start - self.center, radial))
+            .positive();
+
+        if radial.length() > T::zero() && turn <= self.angle {
+            let closest = self.center + radial * (self.radius / radial.length());
+            return Projection::new(
+                point,
+                closest,
+                turn.radians * self.radius,
+                self.heading_at(closest, direction),
+            );
+        }
+
+        // the point is closest to one of the ends
+        if (point - start).length() <= (point - end).length() {
+            Projection::new(point, start, T::zero(), self.heading_at(start, direction))
+        } else {
+            Projection::new(
+                point,
+                end,
+                self.get_length(),
+                self.heading_at(end, direction),
+            )
+        }
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the closest point of the segment to the point
+    pub fn project(&self, point: Point<T>) -> Projection<T> {
+        match self {
+            Self::Circle {
+                circle,
+                start,
+                direction,
+            } => circle.project(*start, *direction, point),
+            Self::Straight(straight) => straight.project(point),
+        }
+    }
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the closest point of the path to the point
+    ///
+    /// if several points are equally close the one first along the path is taken
+    pub fn project(&self, point: Point<T>) -> Projection<T> {
+        let mut closest: Option<Projection<T>> = None;
+        let mut length = T::zero();
+
+        for segment in self.segments().iter() {
+            let mut projection = segment.project(point);
+            projection.distance = projection.distance + length;
+            length = length + segment.get_length();
+
+            match closest {
+                Some(closest)
+                    if closest.cross_track_error.abs() <= projection.cross_track_error.abs() => {}
+                _ => closest = Some(projection),
+            }
+        }
+
+        // a path always has three segments
+        closest.unwrap()
+    }
+}
@@ -0,0 +1,245 @@
+//! Paths of any number of segments
+//!
+//! A [`SegmentPath`] is a list of circles and straights driven one after another.
+//! Unlike [`Path`] it is not limited to the shapes of the Dubins routes, so it can
+//! hold parts of paths split at a distance (e.g. the already driven part and the
+//! rest when replanning), paths put together from several paths or any other
+//! paths made of circles and straights.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Path, Pose, RouteCCC, RouteCSC, Segment, Transform};
+
+/// Segments driven one after another
+#[derive(Debug, Clone)]
+pub struct SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    pub segments: Vec<Segment<T>>,
+}
+
+impl<T> SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a new path of the segments
+    pub fn new(segments: Vec<Segment<T>>) -> Self {
+        Self { segments }
+    }
+
+    /// get the length of all segments
+    pub fn get_length(&self) -> T {
+        self.segments
+            .iter()
+            .fold(T::zero(), |length, segment| length + segment.get_length())
+    }
+
+    /// get the number of segments
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// check if the path has no segments
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// get the index of the segment the distance along the path lies on
+    /// and the distance from the start of that segment
+    ///
+    /// the distance is clamped to the path, none is returned for a path without segments
+    fn segment_at(&self, distance: T) -> Option<(usize, T)> {
+        let last = self.segments.len().checked_sub(1)?;
+        let mut distance = distance.max(T::zero());
+
+        for (index, segment) in self.segments[..last].iter().enumerate() {
+            if distance <= segment.get_length() {
+                return Some((index, distance));
+            }
+            distance = distance - segment.get_length();
+        }
+
+        Some((last, distance.min(self.segments[last].get_length())))
+    }
+
+    /// get the pose after driving the distance along the path
+    /// (none for a path without segments)
+    ///
+    /// the distance is clamped to the path, so the start and end pose are
+    /// returned for distances before and after the path
+    pub fn pose_at(&self, distance: T) -> Option<Pose<T>> {
+        self.segment_at(distance)
+            .map(|(index, distance)| self.segments[index].pose_at(distance))
+    }
+
+    /// get the curvature after driving the distance along the path
+    /// (positive when turning left, none for a path without segments)
+    pub fn curvature_at(&self, distance: T) -> Option<T> {
+        self.segment_at(distance)
+            .map(|(index, _)| self.segments[index].curvature())
+    }
+
+    /// get poses along the path at most the step apart including the start and end pose
+    /// (empty for a path without segments)
+    pub fn sample(&self, step: T) -> Vec<Pose<T>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let length = self.get_length();
+        let count = (length / step).ceil().to_usize().unwrap_or(0).max(1);
+
+        (0..=count)
+            .filter_map(|index| {
+                let fraction: T = (index as f64 / count as f64).into();
+                self.pose_at(length * fraction)
+            })
+            .collect()
+    }
+
+    /// get the path moved by the (rigid) transform
+    pub fn transform(&self, transform: &Transform<T>) -> Self {
+        Self {
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| segment.transform(transform))
+                .collect(),
+        }
+    }
+
+    /// get the path mirrored about the line through the point of the axis pose in its heading
+    pub fn mirror_about_axis(&self, axis: &Pose<T>) -> Self {
+        Self {
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| segment.mirror_about_axis(axis))
+                .collect(),
+        }
+    }
+
+    /// get the path driven from its end to its start (facing the other way)
+    pub fn reversed(&self) -> Self {
+        Self {
+            segments: self.segments.iter().rev().map(Segment::reversed).collect(),
+        }
+    }
+
+    /// append the segments of the other path, which has to start where this path ends
+    pub fn append(&mut self, other: &Self) {
+        self.segments.extend_from_slice(&other.segments);
+    }
+
+    /// split the path after driving the (clamped) distance into the part up to there
+    /// and the rest
+    ///
+    /// the segment at the distance is split into two shorter segments, one of which
+    /// has no length if the distance is at the start or end of the segment
+    pub fn split_at(&self, distance: T) -> (Self, Self) {
+        match self.segment_at(distance) {
+            Some((index, distance)) => {
+                let (first, second) = self.segments[index].split_at(distance);
+
+                let mut driven = self.segments[..index].to_vec();
+                driven.push(first);
+                let mut rest = vec![second];
+                rest.extend_from_slice(&self.segments[index + 1..]);
+
+                (Self { segments: driven }, Self { segments: rest })
+            }
+            None => (self.clone(), self.clone()),
+        }
+    }
+}
+
+impl<T> From<Path<T>> for SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    fn from(path: Path<T>) -> Self {
+        Self {
+            segments: path.segments().to_vec(),
+        }
+    }
+}
+
+impl<T> From<RouteCSC<T>> for SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    fn from(route_csc: RouteCSC<T>) -> Self {
+        Self::from(Path::CSC(route_csc))
+    }
+}
+
+impl<T> From<RouteCCC<T>> for SegmentPath<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    fn from(route_ccc: RouteCCC<T>) -> Self {
+        Self::from(Path::CCC(route_ccc))
+    }
+}
+
+impl<T> Path<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// split the path after driving the (clamped) distance into the part up to there
+    /// and the rest (see [`SegmentPath::split_at`])
+    pub fn split_at(&self, distance: T) -> (SegmentPath<T>, SegmentPath<T>) {
+        SegmentPath::from(*self).split_at(distance)
+    }
+}
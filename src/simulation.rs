@@ -0,0 +1,194 @@
+//! Kinematic vehicle simulation
+//!
+//! The [`Simulator`] integrates curvature commands exactly (the vehicle drives
+//! on a circle or a straight during every step), so it can be used to test
+//! controllers and to check that the paths really end at the requested pose.
+//!
+//! The curvature is positive when turning left like in the [`controller`](crate::controller) module.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Angle, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Error, Path, Point, Pose, Vector};
+
+/// Kinematic model of the vehicle
+#[derive(Debug, Copy, Clone)]
+pub enum Model<T> {
+    /// vehicle that can drive any curvature (e.g. a differential drive)
+    Unicycle,
+    /// car-like vehicle steering with the front wheels
+    Bicycle {
+        /// distance between the front and the rear axle
+        wheelbase: T,
+        /// maximum steering angle of the front wheels
+        max_steering_angle: Angle<T>,
+    },
+}
+
+impl<T> Model<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// get the curvature the vehicle actually drives when the curvature is commanded
+    pub fn curvature(&self, curvature: T) -> T {
+        match self {
+            Self::Unicycle => curvature,
+            Self::Bicycle {
+                wheelbase,
+                max_steering_angle,
+            } => {
+                let steering_angle = Float::atan(*wheelbase * curvature)
+                    .max(-max_steering_angle.radians)
+                    .min(max_steering_angle.radians);
+                Float::tan(steering_angle) / *wheelbase
+            }
+        }
+    }
+}
+
+/// get the pose after driving the distance with the curvature starting at the pose
+fn drive<T>(pose: &Pose<T>, curvature: T, distance: T) -> Pose<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    let angle = pose.angle.radians;
+    // the heading is clockwise, so a left turn decreases it
+    let end_angle = angle - curvature * distance;
+
+    let offset = if curvature.abs() > T::epsilon() {
+        Vector::new(
+            (Float::cos(end_angle) - Float::cos(angle)) / curvature,
+            (Float::sin(angle) - Float::sin(end_angle)) / curvature,
+        )
+    } else {
+        Vector::new(Float::sin(angle), Float::cos(angle)) * distance
+    };
+
+    Pose::new(pose.point + offset, Angle::radians(end_angle).positive())
+}
+
+/// Simulated vehicle
+#[derive(Debug, Copy, Clone)]
+pub struct Simulator<T> {
+    pub model: Model<T>,
+    pub pose: Pose<T>,
+    /// simulated time
+    pub time: T,
+}
+
+impl<T> Simulator<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// create a simulator with the vehicle standing at the pose
+    pub fn new(model: Model<T>, pose: Pose<T>) -> Self {
+        Self {
+            model,
+            pose,
+            time: T::zero(),
+        }
+    }
+
+    /// drive with the speed and the commanded curvature for the time step
+    pub fn step(&mut self, speed: T, curvature: T, time_step: T) {
+        self.pose = drive(
+            &self.pose,
+            self.model.curvature(curvature),
+            speed * time_step,
+        );
+        self.time = self.time + time_step;
+    }
+}
+
+/// Final pose of an executed path and its deviation from the requested end pose
+#[derive(Debug, Copy, Clone)]
+pub struct Execution<T> {
+    pub pose: Pose<T>,
+    /// time it took to drive the path
+    pub time: T,
+    /// distance between the final and the requested end point
+    pub position_error: T,
+    /// angle between the final and the requested end angle (clockwise)
+    pub angle_error: Angle<T>,
+}
+
+/// drive the path open-loop with the model from the start pose and report how far
+/// the final pose is off the requested end point and end angle
+///
+/// the start pose is not taken from the path, so a path that doesn't start there
+/// is noticed as well (paths of this crate start at (0,0) facing in positive
+/// y-direction, placed paths at the pose they were placed at)
+///
+/// every segment is driven with its curvature in steps of the time step,
+/// the last step of a segment is shortened to end exactly at the segment end
+pub fn execute<T>(
+    path: &Path<T>,
+    model: Model<T>,
+    speed: T,
+    time_step: T,
+    start: &Pose<T>,
+    end_point: Point<T>,
+    end_angle: Angle<T>,
+) -> Result<Execution<T>, Error>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    if speed.is_nan() || speed <= T::zero() {
+        return Err(Error::InvalidSpeed);
+    }
+    if time_step.is_nan() || time_step <= T::zero() {
+        return Err(Error::InvalidTimeStep);
+    }
+
+    let mut simulator = Simulator::new(model, *start);
+
+    for segment in path.segments().iter() {
+        let mut remaining = segment.get_length() / speed;
+        while remaining > T::zero() {
+            let time_step = time_step.min(remaining);
+            simulator.step(speed, segment.curvature(), time_step);
+            remaining = remaining - time_step;
+        }
+    }
+
+    Ok(Execution {
+        pose: simulator.pose,
+        time: simulator.time,
+        position_error: (simulator.pose.point - end_point).length(),
+        angle_error: (simulator.pose.angle - end_angle).signed(),
+    })
+}
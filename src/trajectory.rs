@@ -0,0 +1,227 @@
+//! Time parameterization of paths
+//!
+//! A [`Trajectory`] drives along a path as fast as the [`Limits`] allow,
+//! starting and ending at standstill. On the circles the speed is additionally
+//! capped by the maximum lateral acceleration: v = sqrt(a_lat * radius).
+//!
+//! Every segment of the path is split into an acceleration, a cruise and a
+//! deceleration phase, so the speed profile is trapezoidal per segment.
+
+use std::ops::{Add, Mul};
+
+use euclid::{approxeq::ApproxEq, Trig};
+use num_traits::float::{Float, FloatConst};
+
+use crate::{Error, Path, Pose, Segment};
+
+/// Limits of the vehicle
+#[derive(Debug, Copy, Clone)]
+pub struct Limits<T> {
+    /// maximum speed
+    pub max_speed: T,
+    /// maximum acceleration along the path
+    pub max_acceleration: T,
+    /// maximum deceleration along the path (positive)
+    pub max_deceleration: T,
+    /// maximum lateral (centripetal) acceleration
+    pub max_lateral_acceleration: T,
+}
+
+/// State of the vehicle at a point in time
+#[derive(Debug, Copy, Clone)]
+pub struct State<T> {
+    pub pose: Pose<T>,
+    pub speed: T,
+    /// curvature of the path (positive when turning left)
+    pub curvature: T,
+    /// distance driven along the path
+    pub distance: T,
+}
+
+/// Part of the trajectory with constant acceleration
+#[derive(Debug, Copy, Clone)]
+struct Phase<T> {
+    start_time: T,
+    start_distance: T,
+    start_speed: T,
+    acceleration: T,
+    duration: T,
+}
+
+/// Path with a speed profile
+#[derive(Debug, Clone)]
+pub struct Trajectory<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    path: Path<T>,
+    phases: Vec<Phase<T>>,
+}
+
+impl<T> Trajectory<T>
+where
+    T: Add
+        + Mul
+        + Mul<f64, Output = T>
+        + FloatConst
+        + Float
+        + PartialOrd
+        + From<f64>
+        + ApproxEq<T>
+        + Trig,
+{
+    /// compute the fastest trajectory along the path within the limits
+    /// starting and ending at standstill
+    pub fn new(path: Path<T>, limits: Limits<T>) -> Result<Self, Error> {
+        if !(limits.max_speed > T::zero()
+            && limits.max_acceleration > T::zero()
+            && limits.max_deceleration > T::zero()
+            && limits.max_lateral_acceleration > T::zero())
+        {
+            return Err(Error::InvalidLimits);
+        }
+
+        let segments = path.segments();
+        let two: T = 2.0.into();
+
+        // speed cap and length of every segment
+        let caps: Vec<(T, T)> = segments
+            .iter()
+            .map(|segment| {
+                let cap = match segment {
+                    Segment::Circle { circle, .. } => limits
+                        .max_speed
+                        .min((limits.max_lateral_acceleration * circle.radius).sqrt()),
+                    Segment::Straight(_) => limits.max_speed,
+                };
+                (cap, segment.get_length())
+            })
+            .collect();
+
+        // speeds at the segment boundaries, capped by both adjacent segments
+        let mut speeds = vec![T::zero(); caps.len() + 1];
+        for index in 1..caps.len() {
+            speeds[index] = caps[index - 1].0.min(caps[index].0);
+        }
+
+        // forward pass for the acceleration and backward pass for the deceleration
+        for index in 0..caps.len() {
+            let reachable = (speeds[index] * speeds[index]
+                + two * limits.max_acceleration * caps[index].1)
+                .sqrt();
+            speeds[index + 1] = speeds[index + 1].min(reachable);
+        }
+        for index in (0..caps.len()).rev() {
+            let reachable = (speeds[index + 1] * speeds[index + 1]
+                + two * limits.max_deceleration * caps[index].1)
+                .sqrt();
+            speeds[index] = speeds[index].min(reachable);
+        }
+
+        let mut phases = Vec::new();
+        let mut time = T::zero();
+        let mut distance = T::zero();
+
+        for (index, &(cap, length)) in caps.iter().enumerate() {
+            let (entry, exit) = (speeds[index], speeds[index + 1]);
+            let (acceleration, deceleration) = (limits.max_acceleration, limits.max_deceleration);
+
+            // highest speed where the acceleration and the deceleration meet
+            let peak = ((two * acceleration * deceleration * length
+                + deceleration * entry * entry
+                + acceleration * exit * exit)
+                / (acceleration + deceleration))
+                .sqrt()
+                .min(cap);
+
+            let accelerating =
+                ((peak * peak - entry * entry) / (two * acceleration)).max(T::zero());
+            let decelerating = ((peak * peak - exit * exit) / (two * deceleration)).max(T::zero());
+            let cruising = (length - accelerating - decelerating).max(T::zero());
+
+            for &(phase_length, start_speed, end_speed) in [
+                (accelerating, entry, peak),
+                (cruising, peak, peak),
+                (decelerating, peak, exit),
+            ]
+            .iter()
+            {
+                let speed_sum = start_speed + end_speed;
+                if phase_length <= T::zero() || speed_sum <= T::zero() {
+                    continue;
+                }
+
+                let duration = two * phase_length / speed_sum;
+                phases.push(Phase {
+                    start_time: time,
+                    start_distance: distance,
+                    start_speed,
+                    acceleration: (end_speed - start_speed) / duration,
+                    duration,
+                });
+                time = time + duration;
+                distance = distance + phase_length;
+            }
+        }
+
+        Ok(Self { path, phases })
+    }
+
+    /// get the path of the trajectory
+    pub fn path(&self) -> &Path<T> {
+        &self.path
+    }
+
+    /// get the time it takes to drive the trajectory
+    pub fn duration(&self) -> T {
+        self.phases
+            .last()
+            .map(|phase| phase.start_time + phase.duration)
+            .unwrap_or_else(T::zero)
+    }
+
+    /// get the state at the time since the start of the trajectory
+    ///
+    /// the time is clamped to the trajectory, so the vehicle stands still
+    /// at the start before and at the end after it
+    pub fn state_at(&self, time: T) -> State<T> {
+        let phase = self
+            .phases
+            .iter()
+            .rev()
+            .find(|phase| phase.start_time <= time)
+            .or_else(|| self.phases.first());
+
+        let (distance, speed) = match phase {
+            Some(phase) => {
+                let elapsed = (time - phase.start_time).max(T::zero()).min(phase.duration);
+                let speed = phase.start_speed + phase.acceleration * elapsed;
+                let distance =
+                    phase.start_distance + (phase.start_speed + speed) * elapsed / 2.0.into();
+
+                // standing still before the start and after the end
+                if time < T::zero() || time > phase.start_time + phase.duration {
+                    (distance, T::zero())
+                } else {
+                    (distance, speed)
+                }
+            }
+            None => (T::zero(), T::zero()),
+        };
+
+        State {
+            pose: self.path.pose_at(distance),
+            speed,
+            curvature: self.path.curvature_at(distance),
+            distance,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::batch::*;
+    use dubins_path::*;
+
+    fn poses() -> (Vec<Pose<f64>>, Vec<Pose<f64>>) {
+        let starts = vec![
+            Pose::new(Point::new(0.0, 0.0), Angle::zero()),
+            Pose::new(Point::new(5.0, -3.0), Angle::frac_pi_2()),
+            Pose::new(Point::new(-2.0, 4.0), Angle::radians(2.5)),
+        ];
+        let ends = vec![
+            Pose::new(Point::new(0.0, 10.0), Angle::zero()),
+            Pose::new(Point::new(5.0, 7.0), Angle::pi()),
+            Pose::new(Point::new(1.0, 1.0), Angle::radians(-1.0)),
+        ];
+        (starts, ends)
+    }
+
+    #[test]
+    fn test_relative_pose() {
+        let origin = Pose::new(Point::new(1.0, 2.0), Angle::frac_pi_2());
+        let pose = Pose::new(Point::new(4.0, 2.0), Angle::pi());
+
+        let relative = pose.relative_to(&origin);
+        assert!(relative.approx_eq(Pose::new(Point::new(0.0, 3.0), Angle::frac_pi_2())));
+        assert!(relative.from_relative(&origin).approx_eq(pose));
+    }
+
+    #[test]
+    fn test_lengths_match_single_computation() {
+        let radius = 0.5;
+        let (starts, ends) = poses();
+
+        let mut lengths = vec![42.0];
+        shortest_lengths(radius, &starts, &ends, &mut lengths).unwrap();
+
+        assert_eq!(lengths.len(), starts.len());
+        for ((start, end), length) in starts.iter().zip(&ends).zip(&lengths) {
+            let end = end.relative_to(start);
+            let expected = get_shortest(radius, end.point, end.angle).get_length();
+            assert!((expected - length).abs() < 1e-9);
+        }
+        assert!((lengths[0] - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_paths_match_lengths() {
+        let radius = 0.5;
+        let (starts, ends) = poses();
+
+        let mut lengths = Vec::new();
+        let mut paths = Vec::new();
+        shortest_lengths(radius, &starts, &ends, &mut lengths).unwrap();
+        shortest_paths(radius, &starts, &ends, &mut paths).unwrap();
+
+        assert_eq!(paths.len(), lengths.len());
+        for (path, length) in paths.iter().zip(&lengths) {
+            assert!((path.get_length() - length).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_pose_count_mismatch() {
+        let (starts, ends) = poses();
+        let mut lengths = Vec::new();
+        assert!(shortest_lengths(0.5, &starts, &ends[1..], &mut lengths).is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let radius = 0.5;
+        let (starts, ends) = poses();
+
+        let mut lengths = Vec::new();
+        let mut par_lengths = Vec::new();
+        let mut par_paths = Vec::new();
+        shortest_lengths(radius, &starts, &ends, &mut lengths).unwrap();
+        par_shortest_lengths(radius, &starts, &ends, &mut par_lengths).unwrap();
+        par_shortest_paths(radius, &starts, &ends, &mut par_paths).unwrap();
+
+        for ((length, par_length), par_path) in lengths.iter().zip(&par_lengths).zip(&par_paths) {
+            assert!((length - par_length).abs() < 1e-9);
+            assert!((length - par_path.get_length()).abs() < 1e-9);
+        }
+    }
+}
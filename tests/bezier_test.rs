@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::bezier::*;
+    use dubins_path::segment_path::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// get the largest distance of the curves from the circle
+    fn deviation(beziers: &[CubicBezier<f64>], center: Point<f64>, radius: f64) -> f64 {
+        beziers
+            .iter()
+            .flat_map(|bezier| (0..=1000).map(move |index| bezier.point_at(index as f64 / 1000.0)))
+            .map(|point| ((point - center).length() - radius).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_circle_within_tolerance() {
+        for &(radius, angle) in [(1.0, 0.3), (1.0, 4.0), (7.5, 6.2), (0.2, 2.0)].iter() {
+            let circle = CirclePath {
+                center: Point::new(radius, 0.0),
+                radius,
+                angle: Angle::radians(angle),
+            };
+
+            for &tolerance in [1e-2, 1e-4, 1e-7].iter() {
+                let beziers = circle
+                    .to_beziers(Point::new(0.0, 0.0), Direction::Right, tolerance)
+                    .unwrap();
+
+                assert!(deviation(&beziers, circle.center, radius) <= tolerance);
+                assert!(beziers[0].start.approx_eq(&Point::new(0.0, 0.0)));
+                for pair in beziers.windows(2) {
+                    assert!(pair[0].end.approx_eq(&pair[1].start));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fewest_curves() {
+        // one curve for a quarter circle deviates about 2.7e-4
+        let circle = CirclePath {
+            center: Point::new(-1.0, 0.0),
+            radius: 1.0,
+            angle: Angle::frac_pi_2(),
+        };
+        let start = Point::new(0.0, 0.0);
+
+        let beziers = circle.to_beziers(start, Direction::Left, 1e-3).unwrap();
+        assert_eq!(beziers.len(), 1);
+        let bound = deviation(&beziers, circle.center, 1.0);
+        assert!(bound > 2.5e-4 && bound < 2.8e-4);
+        assert!(beziers[0].end.approx_eq(&Point::new(-1.0, 1.0)));
+
+        assert_eq!(
+            circle
+                .to_beziers(start, Direction::Left, 2e-4)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_path_to_beziers() {
+        for &(end_point, end_angle) in [
+            (Point::new(3.0, -2.0), 2.0),
+            (Point::new(-5.0, 8.0), 4.0),
+            (Point::new(0.5, 0.5), 3.0),
+        ]
+        .iter()
+        {
+            let path = get_shortest(1.0, end_point, Angle::radians(end_angle));
+            let beziers = path.to_beziers(1e-4).unwrap();
+
+            assert!(beziers[0].start.approx_eq(&Point::new(0.0, 0.0)));
+            assert!(beziers[beziers.len() - 1].end.approx_eq(&end_point));
+            for pair in beziers.windows(2) {
+                assert!(pair[0].end.approx_eq(&pair[1].start));
+            }
+
+            // every point of the curves is close to the path
+            for bezier in beziers.iter() {
+                for index in 0..=100 {
+                    let point = bezier.point_at(index as f64 / 100.0);
+                    assert!(path.project(point).cross_track_error.abs() <= 1e-4);
+                }
+            }
+
+            let segment_path = SegmentPath::from(path);
+            assert_eq!(segment_path.to_beziers(1e-4).unwrap().len(), beziers.len());
+        }
+    }
+
+    #[test]
+    fn test_straight_bezier() {
+        let straight = StraightPath {
+            origin: Point::new(1.0, 2.0),
+            vector: Vector::new(3.0, -6.0),
+        };
+        let bezier = straight.to_bezier();
+
+        assert!(bezier.point_at(0.5).approx_eq(&Point::new(2.5, -1.0)));
+        assert!(bezier.point_at(1.0).approx_eq(&Point::new(4.0, -4.0)));
+    }
+
+    #[test]
+    fn test_invalid_tolerance() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+
+        assert!(path.to_beziers(0.0).is_err());
+        assert!(path.to_beziers(-1.0).is_err());
+
+        // deviations below the precision of the points can't be reached
+        assert!(matches!(
+            path.to_beziers(1e-300),
+            Err(Error::InvalidTolerance)
+        ));
+        let circle = CirclePath {
+            center: Point::new(1e3, 0.0),
+            radius: 1e3,
+            angle: Angle::radians(6.0),
+        };
+        let beziers = circle
+            .to_beziers(Point::new(0.0, 0.0), Direction::Right, 1e-12)
+            .unwrap();
+        assert!(beziers.len() < 1000);
+    }
+
+    #[test]
+    fn test_piece_count() {
+        /// bound of the deviation of a piece over the angle (Goldapp, 1991)
+        fn bound(angle: f64) -> f64 {
+            let (sin, cos) = (angle / 4.0).sin_cos();
+            4.0 / 27.0 * sin.powi(6) / (cos * cos)
+        }
+
+        for &(radius, angle) in [(1.0, 0.3), (1.0, 4.0), (7.5, 6.2), (0.2, 2.0)].iter() {
+            let circle = CirclePath {
+                center: Point::new(radius, 0.0),
+                radius,
+                angle: Angle::radians(angle),
+            };
+
+            for exponent in 1..=14 {
+                let tolerance = 10f64.powi(-exponent);
+                let pieces = circle
+                    .to_beziers(Point::new(0.0, 0.0), Direction::Right, tolerance)
+                    .unwrap()
+                    .len();
+
+                // at most a quarter circle and one piece less would be too far off
+                assert!(angle / pieces as f64 <= std::f64::consts::FRAC_PI_2 + 1e-12);
+                assert!(bound(angle / pieces as f64) * radius <= tolerance * (1.0 + 1e-9));
+                if pieces > 1 && angle / (pieces - 1) as f64 <= std::f64::consts::FRAC_PI_2 {
+                    assert!(bound(angle / (pieces - 1) as f64) * radius > tolerance);
+                }
+            }
+        }
+    }
+}
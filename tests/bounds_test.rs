@@ -0,0 +1,126 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common;
+    use dubins_path::segment_path::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn paths() -> Vec<Path<f64>> {
+        let mut paths = common::paths();
+        for &(end_point, end_angle) in
+            [(Point::new(0.5, 0.5), 3.0), (Point::new(0.0, 10.0), 0.0)].iter()
+        {
+            let end_angle = Angle::radians(end_angle);
+            paths.push(get_shortest(1.0, end_point, end_angle));
+            if let Ok(route_ccc) = RouteCCC::lrl(1.0, end_point, end_angle) {
+                paths.push(Path::CCC(route_ccc));
+            }
+        }
+        paths
+    }
+
+    fn samples(path: &Path<f64>) -> Vec<Point<f64>> {
+        (0..=10000)
+            .map(|index| {
+                path.pose_at(path.get_length() * index as f64 / 10000.0)
+                    .point
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bounding_box_is_tight() {
+        for path in paths() {
+            let bounds = path.bounding_box();
+            let points = samples(&path);
+
+            for point in points.iter() {
+                assert!(point.x >= bounds.min.x - 1e-9 && point.x <= bounds.max.x + 1e-9);
+                assert!(point.y >= bounds.min.y - 1e-9 && point.y <= bounds.max.y + 1e-9);
+            }
+
+            // every side is touched by the path
+            let touches = |distance: &dyn Fn(&Point<f64>) -> f64| {
+                points.iter().map(distance).fold(f64::INFINITY, f64::min) < 1e-6
+            };
+            assert!(touches(&|point| point.x - bounds.min.x), "{:?}", path);
+            assert!(touches(&|point| bounds.max.x - point.x), "{:?}", path);
+            assert!(touches(&|point| point.y - bounds.min.y), "{:?}", path);
+            assert!(touches(&|point| bounds.max.y - point.y), "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn test_convex_hull_contains_path() {
+        for path in paths() {
+            let hull = path.convex_hull();
+            assert!(hull.len() >= 3);
+
+            for index in 0..hull.len() {
+                let (from, to) = (hull[index], hull[(index + 1) % hull.len()]);
+                let next = hull[(index + 2) % hull.len()];
+
+                // the hull turns counter clockwise and has every point on the left
+                assert!((to - from).cross(next - to) > 0.0);
+                for point in samples(&path) {
+                    assert!((to - from).cross(point - from) >= -1e-9, "{:?}", path);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_half_circle() {
+        // half a circle to the left around (-1,0) from (0,0) to (-2,0)
+        let segment = Segment::Circle {
+            circle: CirclePath {
+                center: Point::new(-1.0, 0.0),
+                radius: 1.0,
+                angle: Angle::pi(),
+            },
+            start: Point::new(0.0, 0.0),
+            direction: Direction::Left,
+        };
+        let bounds = segment.bounding_box();
+
+        assert!(bounds.min.approx_eq(&Point::new(-2.0, 0.0)));
+        assert!(bounds.max.approx_eq(&Point::new(0.0, 1.0)));
+
+        // the tangents at the ends and in the middle enclose the half circle
+        let hull = segment.convex_hull();
+        for point in hull.iter() {
+            assert!(point.x >= -2.0 - 1e-9 && point.x <= 1e-9);
+            assert!(point.y >= -1e-9 && point.y <= 1.0 + 1e-9);
+        }
+        for corner in [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(-2.0, 1.0),
+            Point::new(-2.0, 0.0),
+        ]
+        .iter()
+        {
+            assert!(hull.iter().any(|point| point.approx_eq(corner)));
+        }
+    }
+
+    #[test]
+    fn test_segment_path_bounds() {
+        let path = get_shortest(1.0, Point::new(-5.0, 8.0), Angle::radians(4.0));
+        let segment_path = SegmentPath::from(path);
+
+        assert_eq!(segment_path.bounding_box(), Some(path.bounding_box()));
+        assert_eq!(segment_path.convex_hull(), path.convex_hull());
+        assert!(SegmentPath::<f64>::new(Vec::new()).bounding_box().is_none());
+
+        let straight = StraightPath {
+            origin: Point::new(1.0, 2.0),
+            vector: Vector::new(-3.0, 4.0),
+        };
+        let bounds = straight.bounding_box();
+        assert!(bounds.min.approx_eq(&Point::new(-2.0, 2.0)));
+        assert!(bounds.max.approx_eq(&Point::new(1.0, 6.0)));
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::clothoid::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn end_poses() -> Vec<(Point<f64>, Angle<f64>)> {
+        let mut poses = Vec::new();
+        for &x in [-6.0, -1.5, 0.0, 0.7, 4.0].iter() {
+            for &y in [-5.0, -0.5, 0.0, 2.0, 9.0].iter() {
+                for &angle in [0.0, 1.0, 2.5, 3.5, 5.5].iter() {
+                    poses.push((Point::new(x, y), Angle::radians(angle)));
+                }
+            }
+        }
+        poses
+    }
+
+    #[test]
+    fn test_paths_reach_end_pose() {
+        for (end_point, end_angle) in end_poses() {
+            let path = shortest_clothoid_path(1.0, 2.0, end_point, end_angle).unwrap();
+            let end = path.pose_at(path.get_length());
+
+            assert!(
+                (end.point - end_point).length() < 1e-9,
+                "{:?} {:?}: ended at {:?}",
+                end_point,
+                end_angle,
+                end
+            );
+            assert!((end.angle - end_angle).signed().radians.abs() < 1e-9);
+            assert!(path.pose_at(0.0).point.approx_eq(&Point::new(0.0, 0.0)));
+        }
+    }
+
+    #[test]
+    fn test_curvature_is_continuous_and_limited() {
+        for (end_point, end_angle) in end_poses() {
+            let path = shortest_clothoid_path(2.0, 0.5, end_point, end_angle).unwrap();
+            let step = 0.01;
+
+            let mut previous = path.curvature_at(0.0);
+            assert!(previous.approx_eq(&0.0));
+            for index in 1..=((path.get_length() / step) as usize) {
+                let curvature = path.curvature_at(index as f64 * step);
+                assert!(curvature.abs() <= 0.5 + 1e-9);
+                assert!((curvature - previous).abs() <= 0.5 * step + 1e-9);
+                previous = curvature;
+            }
+            assert!(path.curvature_at(path.get_length()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_segments_are_connected() {
+        for (end_point, end_angle) in end_poses() {
+            let path = shortest_clothoid_path(1.0, 1.0, end_point, end_angle).unwrap();
+
+            for pair in path.segments.windows(2) {
+                assert!(pair[0].pose_at(pair[0].length).approx_eq(pair[1].start));
+                assert!(pair[0].end_curvature.approx_eq(&pair[1].start_curvature));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sharp_clothoids_approach_dubins_path() {
+        let end_point = Point::new(4.0, 9.0);
+        let end_angle = Angle::radians(2.5);
+        let path = shortest_clothoid_path(1.0, 1e6, end_point, end_angle).unwrap();
+        let shortest = get_shortest(1.0, end_point, end_angle);
+
+        assert_eq!(path.path_type, shortest.path_type());
+        assert!((path.get_length() - shortest.get_length()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_straight_ahead() {
+        let path = shortest_clothoid_path(1.0, 1.0, Point::new(0.0, 10.0), Angle::zero()).unwrap();
+
+        assert!(path.get_length().approx_eq(&10.0));
+        assert!(path.curvature_at(5.0).approx_eq(&0.0));
+    }
+
+    #[test]
+    fn test_clothoid_segment() {
+        // a clothoid with curvature rate 1 reaching curvature 1 turns by 1/2
+        let segment = ClothoidSegment {
+            start: Pose::new(Point::new(0.0, 0.0), Angle::zero()),
+            length: 1.0,
+            start_curvature: 0.0,
+            end_curvature: 1.0,
+        };
+        let end = segment.pose_at(1.0);
+
+        assert!(end
+            .angle
+            .radians
+            .approx_eq(&(2.0 * std::f64::consts::PI - 0.5)));
+        // 1/6 - 1/336 + ... to the left and 1 - 1/40 + ... forward
+        assert!((end.point.x - -0.163_714_0).abs() < 1e-6);
+        assert!((end.point.y - 0.975_287_7).abs() < 1e-6);
+
+        let samples = ClothoidPath {
+            path_type: PathType::LSL,
+            segments: vec![segment],
+        }
+        .sample(0.3);
+        assert_eq!(samples.len(), 5);
+        assert!(samples[4].approx_eq(end));
+    }
+
+    #[test]
+    fn test_long_clothoid() {
+        // a clothoid with curvature rate 1 ends at √π (C(u), S(u)) with u = length / √π,
+        // C(5) = 0.5636311887040122 and S(5) = 0.4991913819171169
+        let root_pi = std::f64::consts::PI.sqrt();
+        let length = 5.0 * root_pi;
+        let segment = ClothoidSegment {
+            start: Pose::new(Point::new(0.0, 0.0), Angle::frac_pi_2()),
+            length,
+            start_curvature: 0.0,
+            end_curvature: length,
+        };
+        let end = segment.pose_at(length);
+
+        assert!((end.point.x - root_pi * 0.563_631_188_704_012_2).abs() < 1e-12);
+        assert!((end.point.y - root_pi * 0.499_191_381_917_116_9).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_low_curvature_rate() {
+        // the clothoids of a turn turn by more than a full loop
+        let end_point = Point::new(30.0, 40.0);
+        let end_angle = Angle::radians(2.0);
+
+        for &rate in [0.2, 0.15, 0.12, 0.1, 0.01].iter() {
+            let path = shortest_clothoid_path(1.0, rate, end_point, end_angle).unwrap();
+            let end = path.pose_at(path.get_length());
+
+            assert!(
+                (end.point - end_point).length() < 1e-9,
+                "{}: {:?}",
+                rate,
+                end
+            );
+            assert!((end.angle - end_angle).signed().radians.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        let end = Point::new(0.0, 10.0);
+        assert!(shortest_clothoid_path(0.0, 1.0, end, Angle::zero()).is_err());
+        assert!(shortest_clothoid_path(1.0, 0.0, end, Angle::zero()).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::commands::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn path() -> Path<f64> {
+        Path::CSC(RouteCSC::rsl(2.0, Point::new(4.0, 12.0), Angle::zero()).unwrap())
+    }
+
+    #[test]
+    fn test_ackermann_commands() {
+        let path = path();
+        let lengths = path.to_normalized().lengths;
+        let commands = ackermann_commands(&path, 2.0, 1.0).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        assert!(commands[0].start_time.approx_eq(&0.0));
+        assert!(commands[0].duration.approx_eq(&lengths[0]));
+        assert!(commands[0].speed.approx_eq(&2.0));
+        assert!(commands[0]
+            .steering_angle
+            .radians
+            .approx_eq(&-0.5_f64.atan()));
+        assert!(commands[1].steering_angle.radians.approx_eq(&0.0));
+        assert!(commands[2]
+            .steering_angle
+            .radians
+            .approx_eq(&0.5_f64.atan()));
+
+        let end = commands[2].start_time + commands[2].duration;
+        assert!(end.approx_eq(&(path.get_length() / 2.0)));
+    }
+
+    #[test]
+    fn test_differential_drive_commands() {
+        let commands = differential_drive_commands(&path(), 2.0, 1.0).unwrap();
+
+        assert_eq!(commands.len(), 3);
+        // right turn with radius 2: the right wheel drives on radius 1.5
+        assert!(commands[0].left_speed.approx_eq(&2.5));
+        assert!(commands[0].right_speed.approx_eq(&1.5));
+        assert!(commands[1].left_speed.approx_eq(&2.0));
+        assert!(commands[1].right_speed.approx_eq(&2.0));
+        assert!(commands[2].left_speed.approx_eq(&1.5));
+        assert!(commands[2].right_speed.approx_eq(&2.5));
+        assert!(commands[1].start_time.approx_eq(&commands[0].duration));
+    }
+
+    #[test]
+    fn test_segments_without_length_are_skipped() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let commands = ackermann_commands(&path, 5.0, 1.0).unwrap();
+
+        assert_eq!(commands.len(), 1);
+        assert!(commands[0].duration.approx_eq(&2.0));
+    }
+
+    #[test]
+    fn test_invalid_speed() {
+        assert!(ackermann_commands(&path(), 0.0, 1.0).is_err());
+        assert!(differential_drive_commands(&path(), -1.0, 1.0).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Fixtures shared by the tests
+// not every test uses every fixture
+#![allow(dead_code)]
+
+use dubins_path::*;
+
+/// get every feasible route from (0,0) facing in positive y-direction to some end poses
+/// with their end pose, some far away and some close with overlapping circles
+pub fn routes() -> Vec<(Pose<f64>, Path<f64>)> {
+    let end_angle = Angle::radians(2.0);
+    let mut routes = Vec::new();
+    for &(end_point, radius) in [
+        (Point::new(3.0, -2.0), 1.0),
+        (Point::new(-5.0, 8.0), 2.0),
+        (Point::new(0.4, 0.6), 1.0),
+        (Point::new(-0.8, 0.1), 0.5),
+    ]
+    .iter()
+    {
+        let paths = vec![
+            RouteCSC::rsr(radius, end_point, end_angle).map(Path::CSC),
+            RouteCSC::rsl(radius, end_point, end_angle).map(Path::CSC),
+            RouteCSC::lsr(radius, end_point, end_angle).map(Path::CSC),
+            RouteCSC::lsl(radius, end_point, end_angle).map(Path::CSC),
+            RouteCCC::rlr(radius, end_point, end_angle).map(Path::CCC),
+            RouteCCC::lrl(radius, end_point, end_angle).map(Path::CCC),
+        ];
+        let end = Pose::new(end_point, end_angle);
+        routes.extend(paths.into_iter().flatten().map(|path| (end, path)));
+    }
+    routes
+}
+
+/// get the paths of every route
+pub fn paths() -> Vec<Path<f64>> {
+    routes().into_iter().map(|(_, path)| path).collect()
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::controller::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// drive along the path with the curvatures returned by the controller
+    /// and return the largest distance to the path and the final pose
+    fn track<F>(path: &Path<f64>, start: Pose<f64>, mut curvature: F) -> (f64, Pose<f64>)
+    where
+        F: FnMut(&Pose<f64>) -> f64,
+    {
+        let step = 0.01;
+        let mut pose = start;
+        let mut max_error: f64 = 0.0;
+
+        for _ in 0..((path.get_length() / step) as usize) {
+            let turn = -curvature(&pose) * step;
+            let heading = pose.angle.radians + turn / 2.0;
+            pose = Pose::new(
+                pose.point + Vector::new(heading.sin(), heading.cos()) * step,
+                Angle::radians(pose.angle.radians + turn),
+            );
+            max_error = max_error.max(path.project(pose.point).cross_track_error.abs());
+        }
+
+        (max_error, pose)
+    }
+
+    fn start() -> Pose<f64> {
+        Pose::new(Point::new(0.0, 0.0), Angle::zero())
+    }
+
+    #[test]
+    fn test_pose_and_curvature_along_path() {
+        let path = Path::CSC(RouteCSC::rsl(1.0, Point::new(4.0, 6.0), Angle::zero()).unwrap());
+        let first = path.to_normalized().lengths;
+
+        assert!(path.pose_at(0.0).approx_eq(start()));
+        assert!(path.curvature_at(first[0] / 2.0).approx_eq(&-1.0));
+        assert!(path.curvature_at(first[0] + first[1] / 2.0).approx_eq(&0.0));
+        assert!(path.curvature_at(path.get_length()).approx_eq(&1.0));
+
+        let end = Pose::new(Point::new(4.0, 6.0), Angle::zero());
+        assert!(path.pose_at(path.get_length()).approx_eq(end));
+        assert!(path.pose_at(path.get_length() + 1.0).approx_eq(end));
+    }
+
+    #[test]
+    fn test_pure_pursuit_on_path() {
+        let path = Path::CSC(RouteCSC::rsr(1.0, Point::new(0.0, 10.0), Angle::zero()).unwrap());
+        let controller = PurePursuit::new(0.5);
+
+        assert!(controller
+            .curvature(&Pose::new(Point::new(0.0, 5.0), Angle::zero()), &path)
+            .approx_eq(&0.0));
+        // right of the path it steers left
+        assert!(controller.curvature(&Pose::new(Point::new(0.5, 5.0), Angle::zero()), &path) > 0.0);
+    }
+
+    #[test]
+    fn test_pure_pursuit_tracks_path() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+        let controller = PurePursuit::new(0.2);
+
+        let offset = Pose::new(Point::new(-0.1, 0.0), Angle::zero());
+        let (max_error, end) = track(&path, offset, |pose| controller.curvature(pose, &path));
+
+        assert!(max_error < 0.15);
+        assert!((end.point - Point::new(3.0, -2.0)).length() < 0.2);
+    }
+
+    #[test]
+    fn test_stanley_on_path() {
+        let path = Path::CSC(RouteCSC::rsr(1.0, Point::new(0.0, 10.0), Angle::zero()).unwrap());
+        let controller = Stanley::new(2.0, 0.1, 0.5, Angle::radians(0.7));
+
+        assert!(controller
+            .curvature(&Pose::new(Point::new(0.0, 5.0), Angle::zero()), 1.0, &path)
+            .approx_eq(&0.0));
+        // right of the path or heading to the right it steers left
+        assert!(
+            controller.curvature(&Pose::new(Point::new(0.5, 5.0), Angle::zero()), 1.0, &path) > 0.0
+        );
+        assert!(
+            controller.curvature(
+                &Pose::new(Point::new(0.0, 5.0), Angle::radians(0.2)),
+                1.0,
+                &path
+            ) > 0.0
+        );
+    }
+
+    #[test]
+    fn test_stanley_tracks_path() {
+        let path =
+            Path::CSC(RouteCSC::lsl(2.0, Point::new(-3.0, 8.0), Angle::radians(5.0)).unwrap());
+        let controller = Stanley::new(2.0, 0.1, 0.5, Angle::radians(0.9));
+
+        let offset = Pose::new(Point::new(0.3, 0.0), Angle::radians(0.2));
+        let (max_error, end) = track(&path, offset, |pose| controller.curvature(pose, 1.0, &path));
+
+        assert!(max_error < 0.4);
+        assert!((end.point - Point::new(-3.0, 8.0)).length() < 0.6);
+    }
+
+    #[test]
+    fn test_stanley_limits_steering_angle() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let controller = Stanley::new(5.0, 0.1, 0.5, Angle::radians(0.4));
+
+        let pose = Pose::new(Point::new(-5.0, 2.0), Angle::zero());
+        assert!(controller
+            .steering_angle(&pose, 1.0, &path)
+            .radians
+            .approx_eq(&-0.4));
+    }
+}
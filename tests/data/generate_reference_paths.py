@@ -0,0 +1,293 @@
+#!/usr/bin/env python3
+"""Generate reference_paths.txt
+
+    pip install dubins mpmath
+    python3 tests/data/generate_reference_paths.py > tests/data/reference_paths.txt
+
+The words and lengths come from the C `dubins` library (Andrew Walker's
+Dubins-Curves, through its Python package `dubins`), which computes every word
+separately so equally long words are listed by the library and not by this
+crate. Every length is checked against the word built geometrically from its
+circle centers, tangent points and arcs with 50 significant digits (mpmath).
+Without the library the geometric lengths are written instead and the header
+says so.
+"""
+
+import platform
+import random
+from importlib import metadata
+
+from mpmath import mp, mpf, atan2, cos, sin, sqrt, pi
+
+try:
+    import dubins
+except ImportError:
+    dubins = None
+
+mp.dps = 50
+
+HEADER = """\
+# Reference shortest Dubins paths
+#
+# Every line is one case:
+#
+#   start_x start_y start_heading end_x end_y end_heading radius words length
+#
+# Headings are in radians counter clockwise from the positive x-axis, the
+# convention of the C `dubins` library and OMPL's DubinsStateSpace (not the one
+# of this crate), so the poses can be fed to them unchanged. The words use L
+# for counter clockwise and S for straight, all words with the shortest length
+# are listed separated by commas.
+#
+# Generated by generate_reference_paths.py (next to this file) with
+# {reference}
+# python {python}, mpmath {mpmath}
+"""
+
+# words in the order of the C library
+WORDS = ["LSL", "LSR", "RSL", "RSR", "RLR", "LRL"]
+
+# differences this small are lost to rounding: arcs this close to a full turn
+# are zero, circles this close to touching touch and words this close to the
+# shortest one are equally long
+SLACK = mpf(10) ** -30
+
+# relative difference of lengths that are equally long: the poses are only
+# exact to double precision (and so are the lengths of the library)
+TIE = 1e-10
+
+
+def normal(vector, turn):
+    """unit normal pointing to the center of a circle driven along the vector"""
+    x, y = vector
+    return (-y, x) if turn == "L" else (y, -x)
+
+
+def center(pose, radius, turn):
+    x, y, heading = pose
+    nx, ny = normal((cos(heading), sin(heading)), turn)
+    return (x + radius * nx, y + radius * ny)
+
+
+def arc(circle, start, end, turn):
+    """get the angle turned from the start to the end point around the circle"""
+    start = atan2(start[1] - circle[1], start[0] - circle[0])
+    end = atan2(end[1] - circle[1], end[0] - circle[0])
+    angle = (end - start if turn == "L" else start - end) % (2 * pi)
+    return mpf(0) if angle > 2 * pi - SLACK else angle
+
+
+def csc(start, end, radius, word):
+    """get the length of the word turning, driving straight and turning"""
+    first, last = word[0], word[2]
+    c1, c2 = center(start, radius, first), center(end, radius, last)
+    vx, vy = c2[0] - c1[0], c2[1] - c1[1]
+    distance = sqrt(vx * vx + vy * vy)
+
+    if first == last:
+        if distance < SLACK:
+            return radius * arc(c1, start[:2], end[:2], first)
+        straight = distance
+        heading = atan2(vy, vx)
+    else:
+        # the centers are the straight plus twice the radius across apart
+        if distance < 2 * radius - SLACK:
+            return None
+        straight = sqrt(max(distance * distance - 4 * radius * radius, mpf(0)))
+        offset = atan2(2 * radius, straight)
+        heading = atan2(vy, vx) + (offset if first == "L" else -offset)
+
+    direction = (cos(heading), sin(heading))
+    n1, n2 = normal(direction, first), normal(direction, last)
+    t1 = (c1[0] - radius * n1[0], c1[1] - radius * n1[1])
+    t2 = (c2[0] - radius * n2[0], c2[1] - radius * n2[1])
+    return radius * (arc(c1, start[:2], t1, first) + arc(c2, t2, end[:2], last)) + straight
+
+
+def ccc(start, end, radius, word):
+    """get the length of the shortest of both middle circles of the word"""
+    outer, inner = word[0], word[1]
+    c1, c3 = center(start, radius, outer), center(end, radius, outer)
+    vx, vy = c3[0] - c1[0], c3[1] - c1[1]
+    distance = sqrt(vx * vx + vy * vy)
+    if distance > 4 * radius + SLACK or distance < SLACK:
+        return None
+
+    # the middle circle touches both outer circles
+    height = sqrt(max(4 * radius * radius - distance * distance / 4, mpf(0)))
+    lengths = []
+    for side in (1, -1):
+        c2 = (
+            (c1[0] + c3[0]) / 2 - side * height * vy / distance,
+            (c1[1] + c3[1]) / 2 + side * height * vx / distance,
+        )
+        t1 = ((c1[0] + c2[0]) / 2, (c1[1] + c2[1]) / 2)
+        t2 = ((c2[0] + c3[0]) / 2, (c2[1] + c3[1]) / 2)
+        lengths.append(
+            radius
+            * (
+                arc(c1, start[:2], t1, outer)
+                + arc(c2, t1, t2, inner)
+                + arc(c3, t2, end[:2], outer)
+            )
+        )
+    return min(lengths)
+
+
+def geometric_lengths(start, end, radius):
+    """get the length of every feasible word built geometrically"""
+    start = tuple(mpf(value) for value in start)
+    end = tuple(mpf(value) for value in end)
+    radius = mpf(radius)
+
+    lengths = {}
+    for word in WORDS:
+        length = (csc if word[1] == "S" else ccc)(start, end, radius, word)
+        if length is not None:
+            lengths[word] = length
+    return lengths
+
+
+def library_lengths(start, end, radius):
+    """get the length of every feasible word computed by the C library"""
+    lengths = {}
+    for word in WORDS:
+        try:
+            path = dubins.path(start, end, radius, getattr(dubins, word))
+        except RuntimeError:
+            path = None
+        if path is not None:
+            lengths[word] = path.path_length()
+    return lengths
+
+
+def shortest(lengths):
+    """get the words that are as long as the shortest one (within the tie) and its length"""
+    length = min(lengths.values())
+    slack = TIE * (1 + length)
+    return [word for word in WORDS if word in lengths and lengths[word] <= length + slack], length
+
+
+def case(start, end, radius):
+    """get the line of the case (the poses are exact, only the line and the library round them)"""
+    geometric_words, geometric_length = shortest(geometric_lengths(start, end, radius))
+
+    if dubins is None:
+        words, length = geometric_words, geometric_length
+    else:
+        words, length = shortest(
+            library_lengths(
+                tuple(float(value) for value in start),
+                tuple(float(value) for value in end),
+                float(radius),
+            )
+        )
+        if abs(length - geometric_length) > TIE * (1 + geometric_length) or set(words) != set(
+            geometric_words
+        ):
+            raise ValueError(
+                "the library disagrees with the geometry for {} {} {}: {} {} != {} {}".format(
+                    start, end, radius, words, length, geometric_words, geometric_length
+                )
+            )
+
+    values = list(start) + list(end) + [radius]
+    return " ".join(repr(float(value)) for value in values) + " {} {}".format(
+        ",".join(words), repr(float(length))
+    )
+
+
+def random_cases(count):
+    generator = random.Random(29)
+    for _ in range(count):
+        radius = generator.choice([0.25, 0.5, 1.0, 2.0, 7.5])
+        x, y = (round(generator.uniform(-20.0, 20.0), 3) for _ in range(2))
+        dx, dy = (generator.uniform(-10.0, 10.0) * radius for _ in range(2))
+        start = (x, y, round(generator.uniform(-3.1416, 3.1416), 4))
+        end = (round(x + dx, 3), round(y + dy, 3), round(generator.uniform(-3.1416, 3.1416), 4))
+        yield case(start, end, radius)
+
+
+HALF = pi / 2
+PI = pi
+
+# end poses and radii of the near-degenerate cases starting at the origin
+DEGENERATE = [
+    ("straight ahead", (5.0, 0.0, 0.0), 1.0),
+    ("straight behind with the same heading", (-5.0, 0.0, 0.0), 1.0),
+    ("left half circle (zero length tangent)", (0.0, 2.0, PI), 1.0),
+    ("right half circle (zero length tangent)", (0.0, -2.0, PI), 1.0),
+    ("end at the start", (0.0, 0.0, 0.0), 1.0),
+    ("almost the start pose", (1e-06, 0.0, 0.0), 1.0),
+    ("tiny lateral offset", (0.0, 1e-06, 0.0), 1.0),
+    ("almost the start pose with a tiny heading change", (1e-06, 1e-06, 1e-06), 1.0),
+    ("end at the start turned by π/2", (0.0, 0.0, HALF), 1.0),
+    ("end at the start turned by π", (0.0, 0.0, PI), 1.0),
+    ("end at the start turned by -π/2", (0.0, 0.0, -HALF), 0.5),
+    ("quarter circle left (tangent circles)", (1.0, 1.0, HALF), 1.0),
+    ("quarter circle right (tangent circles)", (1.0, -1.0, -HALF), 1.0),
+    ("inner tangent of touching circles", (2.0, 2.0, 0.0), 1.0),
+    ("inner tangent of touching circles", (2.0, -2.0, 0.0), 1.0),
+    ("outer circles exactly four radii apart", (0.0, 4.0, PI), 1.0),
+    ("outer circles exactly four radii apart", (0.0, -4.0, PI), 1.0),
+    ("close behind the start with opposite heading", (0.5, 0.0, PI), 1.0),
+    ("beside the start", (-1.0, 0.0, HALF), 1.0),
+    ("close ahead with opposite heading", (3.0, 0.0, PI), 0.75),
+    ("beside the start circle", (0.0, 10.0, 0.0), 1.0),
+    ("beside the start circle", (0.0, -10.0, 0.0), 1.0),
+    ("beside the start circle with opposite heading", (0.0, 10.0, PI), 1.0),
+    ("beside the start circle with opposite heading", (0.0, -10.0, PI), 1.0),
+    ("ahead, turned left", (10.0, 0.0, HALF), 1.0),
+    ("ahead, turned right", (10.0, 0.0, -HALF), 1.0),
+    ("behind, turned left", (-10.0, 0.0, HALF), 1.0),
+]
+
+# start pose and indices of the near-degenerate cases placed there
+PLACED_START = (3.5, -1.25, 2.0)
+PLACED = [0, 2, 13, 15]
+
+
+def placed(end):
+    """move the end pose from the origin to the placed start pose"""
+    x, y, heading = (mpf(value) for value in PLACED_START)
+    ex, ey, eheading = (mpf(value) for value in end)
+    return (
+        x + cos(heading) * ex - sin(heading) * ey,
+        y + sin(heading) * ex + cos(heading) * ey,
+        heading + eheading,
+    )
+
+
+def main():
+    if dubins is None:
+        reference = "the 50-digit geometry (the C dubins library was not installed)"
+    else:
+        reference = "the C dubins library (Python package dubins {})".format(
+            metadata.version("dubins")
+        )
+    print(
+        HEADER.format(
+            reference=reference,
+            python=platform.python_version(),
+            mpmath=metadata.version("mpmath"),
+        )
+    )
+    print("# random poses")
+    for line in random_cases(60):
+        print(line)
+
+    print()
+    print("# near-degenerate poses starting at the origin facing in positive x-direction")
+    for comment, end, radius in DEGENERATE:
+        print("# " + comment)
+        print(case((0.0, 0.0, 0.0), end, radius))
+
+    print()
+    print("# some of the near-degenerate poses from a rotated and translated start pose")
+    for index in PLACED:
+        _, end, radius = DEGENERATE[index]
+        print(case(PLACED_START, placed(end), radius))
+
+
+if __name__ == "__main__":
+    main()
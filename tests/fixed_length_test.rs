@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::fixed_length::*;
+    use dubins_path::simulation::*;
+    use dubins_path::*;
+
+    /// check that the path has the length, reaches the end pose and turns not too tight
+    fn assert_fixed_length(
+        path: &FixedLengthPath<f64>,
+        radius: f64,
+        length: f64,
+        end_point: Point<f64>,
+        end_angle: Angle<f64>,
+    ) {
+        assert!(
+            (path.get_length() - length).abs() < 1e-6,
+            "length {} != {}",
+            path.get_length(),
+            length
+        );
+
+        // the path starts where the loiter circle ends
+        let start = path.path.pose_at(0.0);
+        match path.loiter {
+            Some(loiter) => {
+                assert!(loiter.circle.radius >= radius);
+                let end =
+                    loiter
+                        .circle
+                        .project(Point::new(0.0, 0.0), loiter.direction, start.point);
+                assert!(end.cross_track_error.abs() < 1e-9);
+                assert!((end.distance - loiter.circle.get_length()).abs() < 1e-9);
+                assert!((end.angle - start.angle).signed().radians.abs() < 1e-9);
+            }
+            None => assert!(start.approx_eq(Pose::new(Point::new(0.0, 0.0), Angle::zero()))),
+        }
+
+        let execution = execute(
+            &path.path,
+            Model::Unicycle,
+            1.0,
+            0.1,
+            &start,
+            end_point,
+            end_angle,
+        )
+        .unwrap();
+        assert!(execution.position_error < 1e-6);
+        assert!(execution.angle_error.radians.abs() < 1e-6);
+
+        let step = 0.05;
+        for index in 0..=((path.path.get_length() / step) as usize) {
+            assert!(path.path.curvature_at(index as f64 * step).abs() <= 1.0 / radius + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_shortest_length() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+        let length = shortest_length(1.0, end_point, end_angle);
+
+        let path = fixed_length_path(1.0, length, end_point, end_angle).unwrap();
+        assert_fixed_length(&path, 1.0, length, end_point, end_angle);
+    }
+
+    #[test]
+    fn test_elongated_paths() {
+        for &(end_point, end_angle) in [
+            (Point::new(3.0, -2.0), Angle::radians(2.0)),
+            (Point::new(-5.0, 8.0), Angle::radians(4.0)),
+            (Point::new(0.5, 0.5), Angle::pi()),
+            (Point::new(10.0, 10.0), Angle::frac_pi_2()),
+        ]
+        .iter()
+        {
+            let shortest = shortest_length(1.0, end_point, end_angle);
+
+            for &extra in [0.1, 2.0, 7.5, 40.0].iter() {
+                let length = shortest + extra;
+                let path = fixed_length_path(1.0, length, end_point, end_angle).unwrap();
+                assert_fixed_length(&path, 1.0, length, end_point, end_angle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_straight_ahead_loiters() {
+        // a slightly longer path straight ahead can not be reached by larger radii
+        let end_point = Point::new(0.0, 10.0);
+        let path = fixed_length_path(1.0, 12.0, end_point, Angle::zero()).unwrap();
+
+        assert!(path.loiter.is_some());
+        assert_fixed_length(&path, 1.0, 12.0, end_point, Angle::zero());
+    }
+
+    #[test]
+    fn test_length_too_short() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+        let length = shortest_length(1.0, end_point, end_angle);
+
+        assert!(fixed_length_path(1.0, length - 0.1, end_point, end_angle).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::gcode::*;
+    use dubins_path::segment_path::*;
+    use dubins_path::*;
+    use std::f64::consts::PI;
+
+    fn options() -> GCodeOptions<f64> {
+        GCodeOptions::new(Units::Millimeters, 1200.0, Vector::new(0.0, 0.0))
+    }
+
+    #[test]
+    fn test_segments() {
+        let straight = StraightPath {
+            origin: Point::new(1.0, 2.0),
+            vector: Vector::new(3.0, -0.5),
+        };
+        assert_eq!(straight.to_gcode(&options()), "G1 X4.000 Y1.500 F1200.000");
+
+        // a quarter circle to the right around (2,0) from (0,0) to (2,2)
+        let circle = CirclePath {
+            center: Point::new(2.0, 0.0),
+            radius: 2.0,
+            angle: Angle::frac_pi_2(),
+        };
+        assert_eq!(
+            circle.to_gcode(Point::new(0.0, 0.0), Direction::Right, &options()),
+            "G2 X2.000 Y2.000 I2.000 J0.000 F1200.000"
+        );
+
+        // the same circle to the left ends below the center
+        assert_eq!(
+            circle.to_gcode(Point::new(0.0, 0.0), Direction::Left, &options()),
+            "G3 X2.000 Y-2.000 I2.000 J0.000 F1200.000"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let straight = StraightPath {
+            origin: Point::new(0.0, 0.0),
+            vector: Vector::new(-0.00001, 1.0),
+        };
+        let inches = StraightPath {
+            origin: Point::new(0.0, 0.0),
+            vector: Vector::new(-0.0002, 1.0),
+        };
+
+        // coordinates rounded to zero have no sign
+        assert_eq!(straight.to_gcode(&options()), "G1 X0.000 Y1.000 F1200.000");
+
+        let options = GCodeOptions::new(Units::Inches, 40.0, Vector::new(10.0, -5.0));
+        assert_eq!(inches.to_gcode(&options), "G1 X9.9998 Y-4.0000 F40.0000");
+    }
+
+    #[test]
+    fn test_path_program() {
+        let path = get_shortest(2.0, Point::new(5.0, 7.0), Angle::radians(1.0));
+        let offset = Vector::new(100.0, 50.0);
+        let options = GCodeOptions::new(Units::Millimeters, 600.0, offset);
+        let program = path.to_gcode(&options).unwrap();
+        let lines: Vec<&str> = program.lines().collect();
+
+        assert_eq!(&lines[..4], &["G21", "G90", "G17", "G0 X100.000 Y50.000"]);
+        let moves: Vec<String> = path
+            .segments()
+            .iter()
+            .filter(|segment| segment.get_length() > 0.0)
+            .map(|segment| segment.to_gcode(&options))
+            .collect();
+        assert_eq!(&lines[4..], &moves[..]);
+
+        // the last move ends at the end point moved by the offset
+        let end = Point::new(5.0, 7.0) + offset;
+        assert!(lines[lines.len() - 1].contains(&format!("X{:.3} Y{:.3}", end.x, end.y)));
+
+        assert!(matches!(
+            path.to_gcode(&GCodeOptions::new(Units::Millimeters, 0.0, offset)),
+            Err(Error::InvalidFeedrate)
+        ));
+        assert_eq!(
+            SegmentPath::<f64>::new(Vec::new())
+                .to_gcode(&options)
+                .unwrap(),
+            "G21\nG90\nG17\n"
+        );
+    }
+
+    #[test]
+    fn test_no_moves_in_place() {
+        // the end circle is left over from rounding and would be cut as a full circle
+        let path = get_shortest(1.0, Point::new(-3.0, 0.0), Angle::radians(4.0 * PI / 3.0));
+        let [.., last] = path.segments();
+        assert!(last.get_length() > 0.0 && last.get_length() < 1e-9);
+
+        let program = path.to_gcode(&options()).unwrap();
+        let ends: Vec<&str> = program
+            .lines()
+            .skip(3)
+            .map(|line| {
+                let end = line
+                    .find(" I")
+                    .or_else(|| line.find(" F"))
+                    .unwrap_or(line.len());
+                &line[3..end]
+            })
+            .collect();
+
+        assert_eq!(ends.last(), Some(&"X-3.000 Y0.000"));
+        for pair in ends.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+}
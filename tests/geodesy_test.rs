@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::geodesy::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    #[test]
+    fn test_local_round_trip() {
+        let frame = LocalFrame::new(Geodetic::new(48.137, 11.575, 520.0));
+
+        for &local in [
+            [0.0, 0.0, 0.0],
+            [1500.0, -800.0, 12.0],
+            [-3000.0, 4500.0, -40.0],
+        ]
+        .iter()
+        {
+            let position = frame.to_geodetic(local);
+            let result = frame.to_local(&position);
+
+            for (value, expected) in result.iter().zip(local.iter()) {
+                assert!((value - expected).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_local_offsets() {
+        let origin = Geodetic::new(0.0, 30.0, 0.0);
+        let frame = LocalFrame::new(origin);
+
+        // a minute of latitude at the equator is about 1843 m
+        let [east, north, _] = frame.to_local(&Geodetic::new(1.0 / 60.0, 30.0, 0.0));
+        assert!(east.abs() < 1e-6);
+        assert!((north - 1842.9).abs() < 0.1);
+
+        // a minute of longitude at the equator is about 1855 m
+        let [east, north, _] = frame.to_local(&Geodetic::new(0.0, 30.0 + 1.0 / 60.0, 0.0));
+        assert!((east - 1855.3).abs() < 0.1);
+        assert!(north.abs() < 1e-6);
+
+        let [east, north, up] = frame.to_local(&Geodetic::new(0.0, 30.0, 100.0));
+        assert!(east.abs() < 1e-6 && north.abs() < 1e-6);
+        assert!((up - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_heading_convention() {
+        let frame = LocalFrame::new(Geodetic::new(52.52, 13.405, 34.0));
+        let pose = frame.to_pose(&GeoPose::new(
+            Geodetic::new(52.52, 13.405, 34.0),
+            Angle::degrees(90.0),
+        ));
+
+        // heading east is clockwise from north, along the positive x-axis
+        assert!(pose.point.approx_eq(&Point::new(0.0, 0.0)));
+        assert!((pose.angle.radians.sin() - 1.0).abs() < 1e-9);
+        assert!(pose.angle.radians.cos().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_meridian_convergence() {
+        let latitude: f64 = 47.0;
+        let frame = LocalFrame::new(Geodetic::new(latitude, 8.0, 0.0));
+
+        // north 10 km east of the origin is turned counter clockwise by about the
+        // difference of the longitudes times the sine of the latitude
+        let east = frame.point_to_geodetic(Point::new(10_000.0, 0.0));
+        let pose = frame.to_pose(&GeoPose::new(east, Angle::zero()));
+        let convergence =
+            (east.longitude.to_degrees() - 8.0).to_radians() * latitude.to_radians().sin();
+
+        assert!(convergence > 0.0015);
+        assert!((pose.angle.signed().radians + convergence).abs() < 1e-5);
+
+        // the origin keeps its heading and positions straight north only tilt
+        // with the plane
+        let north = frame.point_to_geodetic(Point::new(0.0, 10_000.0));
+        for &(position, tolerance) in [(frame.origin, 1e-9), (north, 1e-4)].iter() {
+            let pose = frame.to_pose(&GeoPose::new(position, Angle::degrees(30.0)));
+            assert!((pose.angle.to_degrees() - 30.0).abs() < tolerance);
+        }
+
+        // the path ends heading along the corrected heading
+        let start = GeoPose::new(frame.origin, Angle::zero());
+        let end = GeoPose::new(east, Angle::degrees(90.0));
+        let geo_path = get_shortest_geodetic(100.0, &start, &end);
+        let [.., last] = geo_path.path.segments();
+        let reached = last.pose_at(last.get_length());
+        assert!(
+            (reached.angle - frame.to_pose(&end).angle)
+                .signed()
+                .radians
+                .abs()
+                < 1e-9
+        );
+        assert!((reached.angle.to_degrees() - 90.0).abs() > 0.05);
+    }
+
+    #[test]
+    fn test_shortest_geodetic() {
+        let start = GeoPose::new(Geodetic::new(47.3769, 8.5417, 408.0), Angle::degrees(30.0));
+        let end = GeoPose::new(Geodetic::new(47.3790, 8.5450, 408.0), Angle::degrees(200.0));
+        let geo_path = get_shortest_geodetic(50.0, &start, &end);
+
+        let expected = geo_path.frame.to_pose(&end);
+        let [.., last] = geo_path.path.segments();
+        let reached = last.pose_at(last.get_length());
+        assert!(reached
+            .point
+            .approx_eq_eps(&expected.point, &Point::new(1e-6, 1e-6)));
+
+        let positions = geo_path.sample(10.0);
+        let (first, last) = (positions[0], positions[positions.len() - 1]);
+        assert!((first.latitude.to_degrees() - 47.3769).abs() < 1e-9);
+        assert!((first.longitude.to_degrees() - 8.5417).abs() < 1e-9);
+        // the end lies slightly below the tangent plane at the start
+        assert!((last.latitude.to_degrees() - 47.3790).abs() < 1e-7);
+        assert!((last.longitude.to_degrees() - 8.5450).abs() < 1e-7);
+        assert!(positions.len() as f64 >= geo_path.path.get_length() / 10.0);
+    }
+
+    #[test]
+    fn test_geojson() {
+        let start = GeoPose::new(Geodetic::new(47.3769, 8.5417, 408.0), Angle::degrees(0.0));
+        let end = GeoPose::new(Geodetic::new(47.3780, 8.5417, 408.0), Angle::degrees(0.0));
+        let geo_path = get_shortest_geodetic(20.0, &start, &end);
+
+        let geojson = geo_path.to_geojson(5.0);
+        let count = geo_path.sample(5.0).len();
+
+        assert!(geojson.starts_with("{\"type\":\"Feature\""));
+        assert!(geojson.contains("\"type\":\"LineString\""));
+        assert!(geojson.contains(&format!(
+            "\"path_type\":\"{:?}\"",
+            geo_path.path.path_type()
+        )));
+        assert!(geojson.contains(&format!("\"radius\":{}", 20.0)));
+        assert_eq!(geojson.matches("],[").count(), count - 1);
+        assert!(geojson.contains("[8.5417"));
+    }
+}
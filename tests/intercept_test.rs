@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::intercept::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// check that the vehicle arrives at the pose of the target at the intercept time
+    fn assert_intercept(intercept: &Intercept<f64>, speed: f64, target: &Target<f64>) {
+        assert!(intercept.pose.approx_eq(target.pose_at(intercept.time)));
+        assert!(
+            (intercept.path.get_length() - speed * intercept.time).abs() < 1e-6,
+            "length {} != {}",
+            intercept.path.get_length(),
+            speed * intercept.time
+        );
+
+        let end = intercept.path.pose_at(intercept.path.get_length());
+        assert!((end.point - intercept.pose.point).length() < 1e-9);
+        assert!((end.angle - intercept.pose.angle).signed().radians.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_target_pose() {
+        let target = Target::new(Pose::new(Point::new(1.0, 2.0), Angle::frac_pi_2()), 3.0);
+
+        assert!(target
+            .pose_at(2.0)
+            .approx_eq(Pose::new(Point::new(7.0, 2.0), Angle::frac_pi_2())));
+    }
+
+    #[test]
+    fn test_target_driving_away() {
+        // the target drives straight away, so the vehicle needs twice its speed
+        // to meet it after 10
+        let target = Target::new(Pose::new(Point::new(0.0, 10.0), Angle::zero()), 1.0);
+        let intercept = intercept(1.0, 2.0, &target, 100.0).unwrap();
+
+        assert!((intercept.time - 10.0).abs() < 1e-6);
+        assert_intercept(&intercept, 2.0, &target);
+    }
+
+    #[test]
+    fn test_crossing_targets() {
+        for &(target, speed) in [
+            (
+                Target::new(Pose::new(Point::new(10.0, 10.0), Angle::pi()), 1.0),
+                2.0,
+            ),
+            (
+                Target::new(Pose::new(Point::new(-8.0, 3.0), Angle::frac_pi_2()), 0.5),
+                1.0,
+            ),
+            (
+                Target::new(Pose::new(Point::new(5.0, -5.0), Angle::radians(5.0)), 1.5),
+                2.0,
+            ),
+        ]
+        .iter()
+        {
+            let intercept = intercept(1.0, speed, &target, 100.0).unwrap();
+            assert_intercept(&intercept, speed, &target);
+
+            // no earlier time is reachable
+            let earlier = target.pose_at(intercept.time * 0.99);
+            assert!(
+                shortest_length(1.0, earlier.point, earlier.angle) > speed * intercept.time * 0.99
+            );
+        }
+    }
+
+    #[test]
+    fn test_standing_target() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+        let target = Target::new(Pose::new(end_point, end_angle), 0.0);
+        let intercept = intercept(1.0, 2.0, &target, 100.0).unwrap();
+
+        let length = shortest_length(1.0, end_point, end_angle);
+        assert!(intercept.time.approx_eq_eps(&(length / 2.0), &1e-6));
+        assert_intercept(&intercept, 2.0, &target);
+    }
+
+    #[test]
+    fn test_too_fast_target() {
+        let target = Target::new(Pose::new(Point::new(0.0, 10.0), Angle::zero()), 2.0);
+
+        assert!(intercept(1.0, 1.0, &target, 100.0).is_err());
+        assert!(intercept(1.0, 0.0, &target, 100.0).is_err());
+    }
+}
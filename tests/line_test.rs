@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::line::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn lines() -> Vec<Line<f64>> {
+        let mut lines = Vec::new();
+        for &point in [
+            Point::new(5.0, 0.0),
+            Point::new(-3.0, 10.0),
+            Point::new(0.5, -0.5),
+            Point::new(-20.0, -4.0),
+        ]
+        .iter()
+        {
+            for &angle in [0.0, 1.0, 2.5, 3.5, 5.5].iter() {
+                lines.push(Line::new(point, Angle::radians(angle)));
+            }
+        }
+        lines
+    }
+
+    /// get the length of the shortest path to the nearest of many points of the line
+    fn brute_force_length(radius: f64, line: &Line<f64>) -> f64 {
+        (-4000..=4000)
+            .map(|index| {
+                let end = line.pose_at(index as f64 / 100.0);
+                shortest_length(radius, end.point, end.angle)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_line_pose() {
+        let line = Line::new(Point::new(1.0, 2.0), Angle::frac_pi_2());
+
+        assert!(line
+            .pose_at(-3.0)
+            .approx_eq(Pose::new(Point::new(-2.0, 2.0), Angle::frac_pi_2())));
+        assert!(line.distance_to(Point::new(4.0, 7.0)).approx_eq(&3.0));
+    }
+
+    #[test]
+    fn test_paths_end_on_line() {
+        for line in lines() {
+            let path = path_to_line(1.0, &line, None);
+            let end = path.end();
+
+            let offset = end.point - line.pose_at(line.distance_to(end.point)).point;
+            assert!(offset.length() < 1e-6, "{:?}: ended at {:?}", line, end);
+            assert!((end.angle - line.angle).signed().radians.abs() < 1e-9);
+            assert!(path.straight.vector.length().approx_eq(&0.0));
+        }
+    }
+
+    #[test]
+    fn test_paths_are_shortest() {
+        for line in lines() {
+            let length = path_to_line(1.0, &line, None).get_length();
+            let brute_force = brute_force_length(1.0, &line);
+
+            assert!(
+                length <= brute_force + 1e-9,
+                "{:?}: {} > {}",
+                line,
+                length,
+                brute_force
+            );
+            assert!(length > brute_force - 0.1);
+        }
+    }
+
+    /// get the length of the shortest path joining many points of the line at least
+    /// the straight in distance before its point and driving straight to the point
+    fn brute_force_straight_in(radius: f64, line: &Line<f64>, straight_in: f64) -> f64 {
+        (0..=8000)
+            .map(|index| {
+                let distance = -straight_in - index as f64 / 100.0;
+                let end = line.pose_at(distance);
+                shortest_length(radius, end.point, end.angle) - distance
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_straight_in() {
+        for line in lines() {
+            let path = path_to_line(1.0, &line, Some(4.0));
+
+            // the straight starts where the path ends and ends at the point of the line
+            let end = path.path.pose_at(path.path.get_length());
+            assert!(end.point.approx_eq(&path.straight.origin));
+            assert!((end.angle - line.angle).signed().radians.abs() < 1e-9);
+            assert!(path
+                .end()
+                .point
+                .approx_eq_eps(&line.point, &Point::new(1e-9, 1e-9)));
+            assert!(path.straight.vector.length() >= 4.0 - 1e-9);
+            assert!(
+                (path.straight.vector.normalize() - (line.pose_at(1.0).point - line.point))
+                    .length()
+                    < 1e-9
+            );
+
+            let brute_force = brute_force_straight_in(1.0, &line, 4.0);
+            assert!(
+                path.get_length() <= brute_force + 1e-9,
+                "{:?}: {} > {}",
+                line,
+                path.get_length(),
+                brute_force
+            );
+            assert!(path.get_length() > brute_force - 0.1);
+        }
+    }
+
+    #[test]
+    fn test_on_the_line() {
+        let line = Line::new(Point::new(0.0, 5.0), Angle::zero());
+
+        assert!(path_to_line(1.0, &line, None).get_length().approx_eq(&0.0));
+        assert!(path_to_line(1.0, &line, Some(0.0))
+            .get_length()
+            .approx_eq(&5.0));
+        let path = path_to_line(1.0, &line, Some(2.0));
+        assert!(path.get_length().approx_eq(&5.0));
+        assert!(path.straight.vector.length() >= 2.0 - 1e-9);
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::geodesy::*;
+    use dubins_path::mission::*;
+    use dubins_path::*;
+
+    fn geo_path() -> GeoPath<f64> {
+        // a left turn, a straight and another left turn
+        let start = GeoPose::new(Geodetic::new(47.3769, 8.5417, 408.0), Angle::degrees(0.0));
+        let end = GeoPose::new(Geodetic::new(47.3790, 8.5400, 408.0), Angle::degrees(180.0));
+        get_shortest_geodetic(50.0, &start, &end)
+    }
+
+    /// get the distance of the position to the point in the frame
+    fn distance(frame: &LocalFrame<f64>, position: &Geodetic<f64>, point: Point<f64>) -> f64 {
+        let [east, north, _] = frame.to_local(position);
+        (Point::new(east, north) - point).length()
+    }
+
+    /// get the position of a waypoint
+    fn waypoint(item: &MissionItem<f64>) -> Geodetic<f64> {
+        match item {
+            MissionItem::Waypoint(position) => *position,
+            item => panic!("expected a waypoint, got {:?}", item),
+        }
+    }
+
+    #[test]
+    fn test_loiter_turns() {
+        let geo_path = geo_path();
+        let [first, second, third] = geo_path.path.segments();
+        assert!(matches!(first, Segment::Circle { .. }));
+        assert!(matches!(second, Segment::Straight(_)));
+
+        let mission = geo_path
+            .to_mission(&MissionOptions::new(0.5, 60.0, 0.0, true))
+            .unwrap();
+
+        match (mission.items[1], first) {
+            (
+                MissionItem::LoiterTurns {
+                    center,
+                    radius,
+                    turns,
+                    direction,
+                },
+                Segment::Circle {
+                    circle,
+                    direction: circle_direction,
+                    ..
+                },
+            ) => {
+                assert!(distance(&geo_path.frame, &center, circle.center) < 1e-6);
+                assert!((radius - 50.0).abs() < 1e-9);
+                assert!((turns - circle.angle.radians / (2.0 * std::f64::consts::PI)).abs() < 1e-9);
+                assert_eq!(direction, circle_direction);
+            }
+            item => panic!("expected a loiter, got {:?}", item),
+        }
+
+        // the straight ends at a waypoint, the last circle is not followed by a straight
+        let position = waypoint(&mission.items[2]);
+        assert!(distance(&geo_path.frame, &position, third.pose_at(0.0).point) < 1e-6);
+        assert!(mission.items[3..]
+            .iter()
+            .all(|item| matches!(item, MissionItem::Waypoint(_))));
+    }
+
+    #[test]
+    fn test_densified_waypoints() {
+        let geo_path = geo_path();
+        let mission = geo_path
+            .to_mission(&MissionOptions::new(0.5, 60.0, 0.0, false))
+            .unwrap();
+        let polyline = geo_path.path.to_polyline(0.5).unwrap();
+
+        assert_eq!(mission.items.len(), polyline.len());
+        for (item, point) in mission.items.iter().zip(polyline.iter()) {
+            assert!(distance(&geo_path.frame, &waypoint(item), *point) < 1e-6);
+        }
+
+        assert!(matches!(
+            geo_path.to_mission(&MissionOptions::new(0.0, 60.0, 0.0, false)),
+            Err(Error::InvalidTolerance)
+        ));
+    }
+
+    #[test]
+    fn test_waypoint_file() {
+        let mission = geo_path()
+            .to_mission(&MissionOptions::new(0.5, 60.0, 48.0, true))
+            .unwrap();
+        let file = mission.to_waypoint_file();
+        let lines: Vec<&str> = file.lines().collect();
+
+        assert_eq!(lines[0], "QGC WPL 110");
+        assert_eq!(lines.len(), mission.items.len() + 2);
+
+        let home: Vec<&str> = lines[1].split('\t').collect();
+        assert_eq!(home.len(), 12);
+        assert_eq!(&home[..4], &["0", "1", "0", "16"]);
+        // the ellipsoidal height of the home position becomes its height above mean sea level
+        assert_eq!(mission.home.altitude, 408.0);
+        assert_eq!(home[10], "360");
+
+        // counter clockwise loiter with a negative radius, leaving towards the next waypoint
+        let loiter: Vec<&str> = lines[3].split('\t').collect();
+        assert_eq!(&loiter[..4], &["2", "0", "3", "18"]);
+        assert_eq!(&loiter[5..7], &["1", "-50"]);
+        assert_eq!(loiter[10], "60");
+
+        for (index, line) in lines[2..].iter().enumerate() {
+            let fields: Vec<&str> = line.split('\t').collect();
+            assert_eq!(fields[0], (index + 1).to_string());
+            assert_eq!(fields[11], "1");
+        }
+    }
+}
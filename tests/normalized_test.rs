@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::normalized::*;
+    use dubins_path::*;
+
+    fn assert_paths_approx_eq(path: Path<f64>, other: Path<f64>) {
+        match (path, other) {
+            (Path::CSC(route), Path::CSC(other)) => {
+                assert!(route.start.approx_eq(other.start));
+                assert!(route.tangent.approx_eq(other.tangent));
+                assert!(route.end.approx_eq(other.end));
+            }
+            (Path::CCC(route), Path::CCC(other)) => {
+                assert!(route.start.approx_eq(other.start));
+                assert!(route.middle.approx_eq(other.middle));
+                assert!(route.end.approx_eq(other.end));
+            }
+            _ => panic!("{:?} and {:?} are different kinds of paths", path, other),
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (d, alpha, beta) = normalize(0.5, Point::new(0.0, 10.0), Angle::zero());
+        assert!((d - 20.0).abs() < 1e-9);
+        assert!(alpha.abs() < 1e-9);
+        assert!(beta.abs() < 1e-9);
+
+        let lengths = segment_lengths(PathType::LSL, d, alpha, beta).unwrap();
+        assert!(lengths[0].abs() < 1e-9);
+        assert!((lengths[1] - 20.0).abs() < 1e-9);
+        assert!(lengths[2].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_path_type() {
+        let radius = 0.5;
+        let end_point = Point::new(1.5, 0.8);
+        let end_angle = Angle::radians(2.0);
+
+        let routes_csc = [
+            (RouteCSC::rsr(radius, end_point, end_angle), PathType::RSR),
+            (RouteCSC::rsl(radius, end_point, end_angle), PathType::RSL),
+            (RouteCSC::lsr(radius, end_point, end_angle), PathType::LSR),
+            (RouteCSC::lsl(radius, end_point, end_angle), PathType::LSL),
+        ];
+        for (route, path_type) in routes_csc.iter() {
+            assert_eq!(route.as_ref().unwrap().path_type(), *path_type);
+        }
+
+        let end_point = Point::new(0.4, 0.6);
+        let routes_ccc = [
+            (RouteCCC::rlr(radius, end_point, end_angle), PathType::RLR),
+            (RouteCCC::lrl(radius, end_point, end_angle), PathType::LRL),
+        ];
+        for (route, path_type) in routes_ccc.iter() {
+            assert_eq!(route.as_ref().unwrap().path_type(), *path_type);
+        }
+    }
+
+    #[test]
+    fn test_normalized_round_trip() {
+        let radius = 0.7;
+        let origin = Pose::new(Point::new(0.0, 0.0), Angle::zero());
+
+        for x in -5..=5 {
+            for y in -5..=5 {
+                for angle in 0..8 {
+                    let end_point = Point::new(x as f64 * 0.6, y as f64 * 0.5);
+                    let end_angle = Angle::radians(angle as f64 * 0.8);
+                    if x == 0 && y == 0 && angle == 0 {
+                        continue;
+                    }
+
+                    let path = get_shortest(radius, end_point, end_angle);
+                    let normalized = path.to_normalized();
+
+                    // the segment lengths have to match the ones of the normalized formulation
+                    let (d, alpha, beta) = normalize(radius, end_point, end_angle);
+                    let lengths = segment_lengths(normalized.path_type, d, alpha, beta).unwrap();
+                    for (length, expected) in normalized.lengths.iter().zip(&lengths) {
+                        assert!((length - expected).abs() < 1e-6);
+                    }
+                    assert!((normalized.get_length(radius) - path.get_length()).abs() < 1e-9);
+
+                    assert_paths_approx_eq(Path::from_normalized(normalized, origin, radius), path);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_normalized_at_pose() {
+        let radius = 0.5;
+        let start = Pose::new(Point::new(3.0, -2.0), Angle::frac_pi_2());
+        let normalized = NormalizedPath {
+            path_type: PathType::LSR,
+            lengths: [
+                Angle::<f64>::frac_pi_2().radians,
+                4.0,
+                Angle::<f64>::pi().radians,
+            ],
+        };
+
+        let expected = RouteCSC {
+            start: CirclePath {
+                center: Point::new(3.0, -1.5),
+                radius,
+                angle: Angle::frac_pi_2(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(3.5, -1.5),
+                vector: Vector::new(0.0, 2.0),
+            },
+            end: CirclePath {
+                center: Point::new(4.0, 0.5),
+                radius,
+                angle: Angle::pi(),
+            },
+            start_direction: Direction::Left,
+            end_direction: Direction::Right,
+        };
+
+        let path = Path::from_normalized(normalized, start, radius);
+        assert_paths_approx_eq(path, Path::CSC(expected));
+        assert_eq!(path.path_type(), PathType::LSR);
+    }
+
+    #[test]
+    fn test_zero_length_tangent_away_from_origin() {
+        // paths whose tangent (or middle circle) gives no hint of the turning directions
+        for &(path_type, lengths) in [
+            (PathType::RSR, [1.0, 0.0, 2.0]),
+            (PathType::LSL, [1.0, 0.0, 2.0]),
+            (PathType::RSL, [0.5, 0.0, 1.5]),
+            (PathType::LSR, [0.5, 0.0, 1.5]),
+            (PathType::RLR, [1.0, 0.0, 2.0]),
+            (PathType::LRL, [1.0, std::f64::consts::PI, 2.0]),
+        ]
+        .iter()
+        {
+            let normalized = NormalizedPath { path_type, lengths };
+            let at_origin = Path::from_normalized(
+                normalized,
+                Pose::new(Point::new(0.0, 0.0), Angle::zero()),
+                1.5,
+            );
+
+            for start in [
+                Pose::new(Point::new(-10.0, 0.0), Angle::zero()),
+                Pose::new(Point::new(10.0, -4.0), Angle::radians(2.5)),
+            ]
+            .iter()
+            {
+                let path = Path::from_normalized(normalized, *start, 1.5);
+                let end = at_origin.pose_at(at_origin.get_length());
+
+                assert_eq!(path.path_type(), path_type);
+                assert!(path.pose_at(0.0).approx_eq(*start));
+                assert!(path
+                    .pose_at(path.get_length())
+                    .approx_eq(end.from_relative(start)));
+                assert!(path.project(start.point).cross_track_error.abs() < 1e-9);
+                assert!(path.project(start.point).distance.abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// get the point at the distance to the right of the pose
+    fn beside(pose: Pose<f64>, distance: f64) -> Point<f64> {
+        let angle = pose.angle.radians;
+        pose.point + Vector::new(angle.cos(), -angle.sin()) * distance
+    }
+
+    #[test]
+    fn test_offset_segments() {
+        for &(end_point, end_angle) in [
+            (Point::new(3.0, -2.0), 2.0),
+            (Point::new(-5.0, 8.0), 4.0),
+            (Point::new(0.5, 0.5), 3.0),
+        ]
+        .iter()
+        {
+            let path = get_shortest(2.0, end_point, Angle::radians(end_angle));
+
+            for &distance in [0.5, -1.5, 3.0, -5.0].iter() {
+                for segment in path.segments().iter() {
+                    let offset = segment.offset(distance).unwrap();
+                    let (length, offset_length) = (segment.get_length(), offset.get_length());
+
+                    for index in 0..=10 {
+                        let fraction = index as f64 / 10.0;
+                        let pose = segment.pose_at(length * fraction);
+                        let offset_pose = offset.pose_at(offset_length * fraction);
+
+                        assert!(
+                            offset_pose.point.approx_eq(&beside(pose, distance)),
+                            "{:?} by {}: {:?} != {:?}",
+                            segment,
+                            distance,
+                            offset_pose,
+                            beside(pose, distance)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_offset_circle_lengths() {
+        // a left quarter circle with radius 2
+        let segment = Segment::Circle {
+            circle: CirclePath {
+                center: Point::new(-2.0, 0.0),
+                radius: 2.0,
+                angle: Angle::frac_pi_2(),
+            },
+            start: Point::new(0.0, 0.0),
+            direction: Direction::Left,
+        };
+        let quarter = std::f64::consts::FRAC_PI_2;
+
+        // outside the radius grows, inside it shrinks
+        let outer = segment.offset(1.0).unwrap();
+        assert!(outer.get_length().approx_eq(&(3.0 * quarter)));
+        assert!(outer
+            .pose_at(0.0)
+            .approx_eq(Pose::new(Point::new(1.0, 0.0), Angle::zero())));
+        let inner = segment.offset(-1.0).unwrap();
+        assert!(inner.get_length().approx_eq(&quarter));
+
+        // beyond the center the offset runs backwards on the other side
+        let beyond = segment.offset(-3.0).unwrap();
+        assert!(beyond.get_length().approx_eq(&quarter));
+        assert!(beyond
+            .pose_at(0.0)
+            .approx_eq(Pose::new(Point::new(-3.0, 0.0), Angle::pi())));
+
+        // at the center it shrinks to a point
+        assert!(segment.offset(-2.0).is_none());
+    }
+
+    #[test]
+    fn test_offset_path() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let offset = path.offset(-2.0);
+
+        assert!(offset.get_length().approx_eq(&10.0));
+        assert!(offset
+            .pose_at(0.0)
+            .unwrap()
+            .approx_eq(Pose::new(Point::new(-2.0, 0.0), Angle::zero())));
+        assert!(offset
+            .pose_at(10.0)
+            .unwrap()
+            .approx_eq(Pose::new(Point::new(-2.0, 10.0), Angle::zero())));
+
+        // the left turn of radius 1 shrinks to its center
+        let path = get_shortest(
+            1.0,
+            Point::new(-1.0, 1.0),
+            Angle::radians(3.0 * std::f64::consts::FRAC_PI_2),
+        );
+        assert!(path.offset(-1.0).get_length().approx_eq(&0.0));
+    }
+
+    #[test]
+    fn test_offset_straight() {
+        let straight = StraightPath {
+            origin: Point::new(1.0, 2.0),
+            vector: Vector::new(3.0, 4.0),
+        };
+
+        assert!(straight.offset(5.0).approx_eq(StraightPath {
+            origin: Point::new(5.0, -1.0),
+            vector: Vector::new(3.0, 4.0),
+        }));
+    }
+}
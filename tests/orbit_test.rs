@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::orbit::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn orbits() -> Vec<Orbit<f64>> {
+        let mut orbits = Vec::new();
+        for &center in [
+            Point::new(0.0, 20.0),
+            Point::new(8.0, -6.0),
+            Point::new(-15.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(-2.5, 0.5),
+        ]
+        .iter()
+        {
+            for &radius in [1.0, 3.0].iter() {
+                for &direction in [Direction::Left, Direction::Right].iter() {
+                    orbits.push(Orbit::new(center, radius, direction));
+                }
+            }
+        }
+        orbits
+    }
+
+    /// get the length of the shortest path to the nearest of many points of the orbit
+    fn brute_force_length(radius: f64, orbit: &Orbit<f64>) -> f64 {
+        (0..3600)
+            .map(|index| {
+                let end = orbit.pose_at(Angle::degrees(index as f64 / 10.0));
+                shortest_length(radius, end.point, end.angle)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_orbit_pose() {
+        let left = Orbit::new(Point::new(1.0, 2.0), 3.0, Direction::Left);
+        let right = Orbit::new(Point::new(1.0, 2.0), 3.0, Direction::Right);
+
+        assert!(left
+            .pose_at(Angle::zero())
+            .approx_eq(Pose::new(Point::new(4.0, 2.0), Angle::zero())));
+        assert!(right
+            .pose_at(Angle::frac_pi_2())
+            .approx_eq(Pose::new(Point::new(1.0, 5.0), Angle::frac_pi_2())));
+    }
+
+    #[test]
+    fn test_paths_end_on_orbit() {
+        for orbit in orbits() {
+            let path = path_to_orbit(1.0, &orbit).unwrap();
+            let end = path.pose_at(path.get_length());
+
+            // the end point lies on the orbit and heads along it
+            let radial = end.point - orbit.center;
+            assert!(radial.length().approx_eq_eps(&orbit.radius, &1e-6));
+            let expected = orbit.pose_at(Angle::radians(radial.y.atan2(radial.x)));
+            assert!(
+                (end.angle - expected.angle).signed().radians.abs() < 1e-6,
+                "{:?}: ended at {:?}",
+                orbit,
+                end
+            );
+        }
+    }
+
+    #[test]
+    fn test_paths_are_shortest() {
+        for orbit in orbits() {
+            let length = path_to_orbit(1.0, &orbit).unwrap().get_length();
+            let brute_force = brute_force_length(1.0, &orbit);
+
+            assert!(
+                length <= brute_force + 1e-9,
+                "{:?}: {} > {}",
+                orbit,
+                length,
+                brute_force
+            );
+            assert!(length > brute_force - 0.1);
+        }
+    }
+
+    #[test]
+    fn test_dense_search() {
+        // the best points of some of these lie where a route becomes infeasible,
+        // the ones close to the start end with a ccc route
+        for &(x, y, radius, direction) in [
+            (-3.4, 2.6, 3.0, Direction::Right),
+            (-1.7, 3.9, 3.0, Direction::Right),
+            (1.7, -2.6, 3.0, Direction::Left),
+            (5.1, 1.3, 3.0, Direction::Left),
+            (1.9, 1.0, 1.6, Direction::Left),
+            (-0.3, 0.4, 1.2, Direction::Left),
+            (-0.1, 0.9, 1.7, Direction::Right),
+            (-0.7, 0.8, 1.0, Direction::Left),
+        ]
+        .iter()
+        {
+            let orbit = Orbit::new(Point::new(x, y), radius, direction);
+            let length = path_to_orbit(1.0, &orbit).unwrap().get_length();
+            let dense = (0..360_000)
+                .map(|index| {
+                    let end = orbit.pose_at(Angle::degrees(index as f64 / 1000.0));
+                    shortest_length(1.0, end.point, end.angle)
+                })
+                .fold(f64::INFINITY, f64::min);
+
+            assert!(
+                (length - dense).abs() < 1e-4,
+                "{:?}: {} != {}",
+                orbit,
+                length,
+                dense
+            );
+        }
+    }
+
+    #[test]
+    fn test_turning_onto_orbit() {
+        // turning onto the orbit is shorter than driving straight onto its left side
+        let orbit = Orbit::new(Point::new(5.0, 20.0), 5.0, Direction::Right);
+        let path = path_to_orbit(1.0, &orbit).unwrap();
+
+        assert!(path.get_length() < 20.0);
+        assert!(path.curvature_at(path.get_length()).approx_eq(&1.0));
+    }
+
+    #[test]
+    fn test_invalid_orbit() {
+        let center = Point::new(0.0, 20.0);
+
+        // the vehicle can't fly an orbit tighter than its turning radius
+        for &(radius, orbit_radius) in [
+            (1.0, 0.2),
+            (1.0, 0.0),
+            (0.0, 3.0),
+            (-1.0, 3.0),
+            (1.0, f64::NAN),
+            (f64::NAN, 3.0),
+        ]
+        .iter()
+        {
+            let orbit = Orbit::new(center, orbit_radius, Direction::Left);
+            assert!(matches!(
+                path_to_orbit(radius, &orbit),
+                Err(Error::InvalidOrbit)
+            ));
+        }
+
+        // an orbit with the turning radius can be entered
+        let orbit = Orbit::new(center, 1.0, Direction::Left);
+        let path = path_to_orbit(1.0, &orbit).unwrap();
+        assert!(path.get_length() <= brute_force_length(1.0, &orbit) + 1e-9);
+    }
+}
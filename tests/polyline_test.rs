@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::segment_path::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// get the distance of the point to the line segment between the points
+    fn distance_to_chord(point: Point<f64>, from: Point<f64>, to: Point<f64>) -> f64 {
+        let chord = to - from;
+        let along = ((point - from).dot(chord) / chord.square_length()).clamp(0.0, 1.0);
+        (point - (from + chord * along)).length()
+    }
+
+    #[test]
+    fn test_polyline_within_tolerance() {
+        for &(end_point, end_angle) in [
+            (Point::new(3.0, -2.0), 2.0),
+            (Point::new(-5.0, 8.0), 4.0),
+            (Point::new(0.5, 0.5), 3.0),
+        ]
+        .iter()
+        {
+            let path = get_shortest(2.0, end_point, Angle::radians(end_angle));
+
+            for &tolerance in [0.5, 0.01, 1e-4].iter() {
+                let polyline = path.to_polyline(tolerance).unwrap();
+                assert!(polyline[0].approx_eq(&Point::new(0.0, 0.0)));
+                assert!(polyline[polyline.len() - 1].approx_eq(&end_point));
+
+                // every point of the path is close to the polyline
+                for index in 0..=2000 {
+                    let point = path
+                        .pose_at(path.get_length() * index as f64 / 2000.0)
+                        .point;
+                    let distance = polyline
+                        .windows(2)
+                        .map(|chord| distance_to_chord(point, chord[0], chord[1]))
+                        .fold(f64::INFINITY, f64::min);
+                    assert!(distance <= tolerance + 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_points_only_where_needed() {
+        // a straight needs no points in between
+        let path = get_shortest(1.0, Point::new(0.0, 100.0), Angle::zero());
+        assert_eq!(path.to_polyline(1e-3).unwrap().len(), 2);
+
+        // a quarter circle with radius 1 deviates 1 - cos(π/8) with two chords
+        let path = get_shortest(1.0, Point::new(1.0, 1.0), Angle::frac_pi_2());
+        let sagitta = 1.0 - (std::f64::consts::PI / 8.0).cos();
+        assert_eq!(path.to_polyline(sagitta + 1e-9).unwrap().len(), 3);
+        assert_eq!(path.to_polyline(sagitta - 1e-9).unwrap().len(), 4);
+
+        // tighter circles need more points
+        let wide = get_shortest(5.0, Point::new(5.0, 5.0), Angle::frac_pi_2());
+        let tight = get_shortest(1.0, Point::new(1.0, 1.0), Angle::frac_pi_2());
+        assert!(wide.to_polyline(0.01).unwrap().len() > tight.to_polyline(0.01).unwrap().len());
+    }
+
+    #[test]
+    fn test_segment_path_polyline() {
+        let path = get_shortest(1.0, Point::new(-5.0, 8.0), Angle::radians(4.0));
+        let segment_path = SegmentPath::from(path);
+
+        assert_eq!(
+            segment_path.to_polyline(0.01).unwrap(),
+            path.to_polyline(0.01).unwrap()
+        );
+        assert!(SegmentPath::<f64>::new(Vec::new())
+            .to_polyline(0.01)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_tolerance() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+
+        assert!(path.to_polyline(0.0).is_err());
+        assert!(path.to_polyline(-1.0).is_err());
+        assert!(path.segments()[0].to_polyline(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_tolerance_precision() {
+        let path = get_shortest(1.0, Point::new(1.0, 1.0), Angle::frac_pi_2());
+
+        // below the precision relative to the radius the points can't be placed
+        for &tolerance in [1e-17, 1e-300, f64::MIN_POSITIVE].iter() {
+            assert!(matches!(
+                path.to_polyline(tolerance),
+                Err(Error::InvalidTolerance)
+            ));
+        }
+        let wide = get_shortest(1e3, Point::new(1e3, 1e3), Angle::frac_pi_2());
+        assert!(matches!(
+            wide.to_polyline(1e-14),
+            Err(Error::InvalidTolerance)
+        ));
+
+        // just above it a short arc needs angle / 4 asin(√(tolerance / 2)) chords
+        let tolerance = 1e-15;
+        let segment = Segment::Circle {
+            circle: CirclePath {
+                center: Point::new(-1.0, 0.0),
+                radius: 1.0,
+                angle: Angle::radians(1e-5),
+            },
+            start: Point::new(0.0, 0.0),
+            direction: Direction::Left,
+        };
+        let points = segment.to_polyline(tolerance).unwrap();
+        let chords = (1e-5 / (4.0 * (tolerance / 2.0).sqrt().asin())).ceil();
+        assert_eq!(points.len(), chords as usize + 1);
+        for pair in points.windows(2) {
+            let middle = pair[0].lerp(pair[1], 0.5);
+            // the points and the distance are only exact up to their rounding
+            let deviation = 1.0 - (middle - Point::new(-1.0, 0.0)).length();
+            assert!(deviation <= tolerance + 4.0 * f64::EPSILON);
+        }
+
+        // tolerances beyond the diameter need a single chord per segment
+        for &tolerance in [2.0, 1e3, f64::MAX, f64::INFINITY].iter() {
+            assert_eq!(path.to_polyline(tolerance).unwrap().len(), 2);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn quarter_circle() -> CirclePath<f64> {
+        // right turn starting at (0,0) facing in positive y-direction ending at (1,1)
+        CirclePath {
+            center: Point::new(1.0, 0.0),
+            radius: 1.0,
+            angle: Angle::frac_pi_2(),
+        }
+    }
+
+    #[test]
+    fn test_project_onto_straight() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+
+        let right = path.project(Point::new(1.0, 5.0));
+        assert!(right.point.approx_eq(&Point::new(0.0, 5.0)));
+        assert!(right.distance.approx_eq(&5.0));
+        assert!(right.angle.radians.approx_eq(&0.0));
+        assert!(right.cross_track_error.approx_eq(&1.0));
+
+        let left = path.project(Point::new(-2.0, 5.0));
+        assert!(left.point.approx_eq(&Point::new(0.0, 5.0)));
+        assert!(left.cross_track_error.approx_eq(&-2.0));
+    }
+
+    #[test]
+    fn test_project_beyond_straight_ends() {
+        let straight = StraightPath {
+            origin: Point::new(0.0, 0.0),
+            vector: Vector::new(0.0, 4.0),
+        };
+
+        let before = straight.project(Point::new(0.0, -3.0));
+        assert!(before.point.approx_eq(&Point::new(0.0, 0.0)));
+        assert!(before.distance.approx_eq(&0.0));
+        assert!(before.cross_track_error.approx_eq(&3.0));
+
+        let after = straight.project(Point::new(0.0, 7.0));
+        assert!(after.point.approx_eq(&Point::new(0.0, 4.0)));
+        assert!(after.distance.approx_eq(&4.0));
+    }
+
+    #[test]
+    fn test_project_onto_circle() {
+        let circle = quarter_circle();
+        let start = Point::new(0.0, 0.0);
+        let halfway = std::f64::consts::FRAC_PI_4;
+
+        // outside of the arc is on the left side of a right turn
+        let outside = circle.project(start, Direction::Right, Point::new(-1.0, 2.0));
+        assert!(outside
+            .point
+            .approx_eq(&Point::new(1.0 - halfway.cos(), halfway.sin())));
+        assert!(outside.distance.approx_eq(&halfway));
+        assert!(outside.angle.radians.approx_eq(&halfway));
+        assert!(outside.cross_track_error < 0.0);
+        assert!(outside.cross_track_error.approx_eq(&(1.0 - 8.0_f64.sqrt())));
+
+        // inside of the arc is on the right side of a right turn
+        let inside = circle.project(start, Direction::Right, Point::new(0.5, 0.5));
+        assert!(inside.distance.approx_eq(&halfway));
+        assert!(inside.cross_track_error.approx_eq(&(1.0 - 0.5_f64.sqrt())));
+    }
+
+    #[test]
+    fn test_project_beyond_circle_ends() {
+        let circle = quarter_circle();
+        let start = Point::new(0.0, 0.0);
+
+        let before = circle.project(start, Direction::Right, Point::new(0.0, -1.0));
+        assert!(before.point.approx_eq(&start));
+        assert!(before.distance.approx_eq(&0.0));
+        assert!(before.angle.radians.approx_eq(&0.0));
+
+        let after = circle.project(start, Direction::Right, Point::new(3.0, 1.0));
+        assert!(after.point.approx_eq(&Point::new(1.0, 1.0)));
+        assert!(after.distance.approx_eq(&circle.get_length()));
+        assert!(after.angle.radians.approx_eq(&std::f64::consts::FRAC_PI_2));
+        // straight ahead of the end is neither left nor right
+        assert!(after.cross_track_error.approx_eq(&2.0));
+    }
+
+    #[test]
+    fn test_points_on_path_have_no_error() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+
+        for path in [
+            Path::CSC(RouteCSC::rsl(1.0, end_point, end_angle).unwrap()),
+            Path::CCC(RouteCCC::lrl(1.0, Point::new(0.4, 0.6), end_angle).unwrap()),
+        ]
+        .iter()
+        {
+            let end = match path {
+                Path::CSC(route_csc) => route_csc.end.center,
+                Path::CCC(route_ccc) => route_ccc.end.center,
+            };
+            let projection = path.project(Point::new(0.0, 0.0));
+            assert!(projection.distance.approx_eq(&0.0));
+            assert!(projection.cross_track_error.approx_eq(&0.0));
+
+            // the end circle center projects somewhere onto the path at distance radius
+            let projection = path.project(end);
+            assert!(projection.cross_track_error.abs() <= 1.0 + 1e-9);
+            assert!(projection.distance <= path.get_length() + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::*;
+
+    const REFERENCE_PATHS: &str = include_str!("data/reference_paths.txt");
+
+    struct ReferenceCase {
+        line: usize,
+        start: Pose<f64>,
+        end: Pose<f64>,
+        radius: f64,
+        /// all words with the shortest length
+        path_types: Vec<PathType>,
+        length: f64,
+    }
+
+    /// convert a heading counter clockwise from the positive x-axis
+    /// into one clockwise from the positive y-axis
+    fn heading(angle: f64) -> Angle<f64> {
+        (Angle::frac_pi_2() - Angle::radians(angle)).positive()
+    }
+
+    fn reference_cases() -> Vec<ReferenceCase> {
+        REFERENCE_PATHS
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(index, line)| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                assert_eq!(fields.len(), 9, "line {}: {}", index + 1, line);
+
+                let number = |field: usize| -> f64 {
+                    fields[field]
+                        .parse()
+                        .unwrap_or_else(|_| panic!("line {}: invalid number", index + 1))
+                };
+                let path_types = fields[7]
+                    .split(',')
+                    .map(|word| match word {
+                        "RSR" => PathType::RSR,
+                        "RSL" => PathType::RSL,
+                        "LSR" => PathType::LSR,
+                        "LSL" => PathType::LSL,
+                        "RLR" => PathType::RLR,
+                        "LRL" => PathType::LRL,
+                        word => panic!("line {}: unknown word {}", index + 1, word),
+                    })
+                    .collect();
+
+                ReferenceCase {
+                    line: index + 1,
+                    start: Pose::new(Point::new(number(0), number(1)), heading(number(2))),
+                    end: Pose::new(Point::new(number(3), number(4)), heading(number(5))),
+                    radius: number(6),
+                    path_types,
+                    length: number(8),
+                }
+            })
+            .collect()
+    }
+
+    /// the reference lengths are exact to double precision
+    fn tolerance(length: f64) -> f64 {
+        1e-10 * length
+    }
+
+    #[test]
+    fn test_reference_corpus_is_not_empty() {
+        assert!(reference_cases().len() >= 80);
+    }
+
+    #[test]
+    fn test_get_shortest_matches_reference() {
+        for case in reference_cases() {
+            let end = case.end.relative_to(&case.start);
+            let path = get_shortest(case.radius, end.point, end.angle);
+
+            assert!(
+                (path.get_length() - case.length).abs() <= tolerance(case.length),
+                "line {}: length {} != {}",
+                case.line,
+                path.get_length(),
+                case.length
+            );
+
+            // the reference lists every word that is just as long
+            assert!(
+                case.path_types.contains(&path.path_type()),
+                "line {}: word {:?} not in {:?}",
+                case.line,
+                path.path_type(),
+                case.path_types
+            );
+        }
+    }
+
+    #[test]
+    fn test_shortest_length_matches_reference() {
+        for case in reference_cases() {
+            let end = case.end.relative_to(&case.start);
+            let length = shortest_length(case.radius, end.point, end.angle);
+
+            assert!(
+                (length - case.length).abs() <= tolerance(case.length),
+                "line {}: length {} != {}",
+                case.line,
+                length,
+                case.length
+            );
+        }
+    }
+}
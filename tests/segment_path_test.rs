@@ -0,0 +1,219 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common;
+    use dubins_path::segment_path::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn paths() -> Vec<Path<f64>> {
+        let mut paths = common::paths();
+        paths.push(get_shortest(1.0, Point::new(0.5, 0.5), Angle::pi()));
+        paths
+    }
+
+    #[test]
+    fn test_split_keeps_poses() {
+        for path in paths() {
+            let length = path.get_length();
+
+            for &fraction in [0.0, 0.1, 0.35, 0.5, 0.8, 1.0].iter() {
+                let distance = length * fraction;
+                let (driven, rest) = path.split_at(distance);
+
+                assert!(driven.get_length().approx_eq(&distance));
+                assert!(rest.get_length().approx_eq(&(length - distance)));
+
+                for index in 0..=10 {
+                    let along = distance * index as f64 / 10.0;
+                    assert!(driven
+                        .pose_at(along)
+                        .unwrap()
+                        .approx_eq(path.pose_at(along)));
+
+                    let along = (length - distance) * index as f64 / 10.0;
+                    assert!(rest
+                        .pose_at(along)
+                        .unwrap()
+                        .approx_eq(path.pose_at(distance + along)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_twice() {
+        let path = SegmentPath::from(get_shortest(
+            1.0,
+            Point::new(-5.0, 8.0),
+            Angle::radians(4.0),
+        ));
+        let (first, rest) = path.split_at(2.0);
+        let (second, third) = rest.split_at(3.0);
+
+        assert!(first.get_length().approx_eq(&2.0));
+        assert!(second.get_length().approx_eq(&3.0));
+        assert!(third
+            .pose_at(0.0)
+            .unwrap()
+            .approx_eq(path.pose_at(5.0).unwrap()));
+        assert!(second
+            .curvature_at(1.0)
+            .unwrap()
+            .approx_eq(&path.curvature_at(3.0).unwrap()));
+    }
+
+    #[test]
+    fn test_splice_new_plan() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+        let (mut driven, _) = path.split_at(2.5);
+
+        // plan from the pose at the split to a new end pose and put it behind the driven part
+        let origin = driven.pose_at(driven.get_length()).unwrap();
+        let end = Pose::new(Point::new(10.0, 4.0), Angle::frac_pi_2());
+        let relative = end.relative_to(&origin);
+        let plan = get_shortest(1.0, relative.point, relative.angle).transform(
+            &Transform::rotation(-origin.angle).then_translate(origin.point.to_vector()),
+        );
+        driven.append(&SegmentPath::from(plan));
+
+        assert!(driven.get_length().approx_eq(&(2.5 + plan.get_length())));
+        assert!(driven.pose_at(2.5).unwrap().approx_eq(origin));
+        assert!(driven.pose_at(driven.get_length()).unwrap().approx_eq(end));
+    }
+
+    #[test]
+    fn test_split_clamps_distance() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+
+        let (driven, rest) = path.split_at(-1.0);
+        assert!(driven.get_length().approx_eq(&0.0));
+        assert!(rest.get_length().approx_eq(&path.get_length()));
+
+        let (driven, rest) = path.split_at(100.0);
+        assert!(driven.get_length().approx_eq(&path.get_length()));
+        assert!(rest.get_length().approx_eq(&0.0));
+        assert_eq!(driven.len() + rest.len(), 4);
+    }
+
+    #[test]
+    fn test_route_conversions() {
+        let end_point = Point::new(0.4, 0.6);
+        let end_angle = Angle::radians(2.0);
+        let route_csc = RouteCSC::rsr(1.0, end_point, end_angle).unwrap();
+        let route_ccc = RouteCCC::lrl(1.0, end_point, end_angle).unwrap();
+
+        for (path, segment_path) in [
+            (Path::CSC(route_csc), SegmentPath::from(route_csc)),
+            (Path::CCC(route_ccc), SegmentPath::from(route_ccc)),
+        ]
+        .iter()
+        {
+            assert_eq!(segment_path.len(), 3);
+            assert!(segment_path.get_length().approx_eq(&path.get_length()));
+
+            let samples = segment_path.sample(0.1);
+            assert!(samples[0].approx_eq(Pose::new(Point::new(0.0, 0.0), Angle::zero())));
+            assert!(samples[samples.len() - 1].approx_eq(Pose::new(end_point, end_angle)));
+            for pair in samples.windows(2) {
+                assert!((pair[1].point - pair[0].point).length() <= 0.1 + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_segments() {
+        // a quarter circle to the right followed by a straight and a half circle to the left
+        let path = SegmentPath::new(vec![
+            Segment::Circle {
+                circle: CirclePath {
+                    center: Point::new(2.0, 0.0),
+                    radius: 2.0,
+                    angle: Angle::frac_pi_2(),
+                },
+                start: Point::new(0.0, 0.0),
+                direction: Direction::Right,
+            },
+            Segment::Straight(StraightPath {
+                origin: Point::new(2.0, 2.0),
+                vector: Vector::new(3.0, 0.0),
+            }),
+            Segment::Circle {
+                circle: CirclePath {
+                    center: Point::new(5.0, 3.0),
+                    radius: 1.0,
+                    angle: Angle::pi(),
+                },
+                start: Point::new(5.0, 2.0),
+                direction: Direction::Left,
+            },
+        ]);
+
+        assert!(path
+            .get_length()
+            .approx_eq(&(2.0 * std::f64::consts::PI + 3.0)));
+        assert!(path
+            .pose_at(path.get_length())
+            .unwrap()
+            .approx_eq(Pose::new(
+                Point::new(5.0, 4.0),
+                Angle::radians(3.0 * std::f64::consts::FRAC_PI_2)
+            )));
+        assert!(path.curvature_at(0.5).unwrap().approx_eq(&-0.5));
+        assert!(path
+            .curvature_at(path.get_length())
+            .unwrap()
+            .approx_eq(&1.0));
+    }
+
+    #[test]
+    fn test_reversed_and_transformed() {
+        let path = SegmentPath::from(get_shortest(
+            1.0,
+            Point::new(-5.0, 8.0),
+            Angle::radians(4.0),
+        ));
+        let length = path.get_length();
+        let reversed = path.reversed();
+        let transform =
+            Transform::rotation(Angle::radians(0.7)).then_translate(Vector::new(4.0, -1.0));
+        let mirrored = path.mirror_about_axis(&Pose::new(Point::new(0.0, 0.0), Angle::zero()));
+
+        for index in 0..=20 {
+            let distance = length * index as f64 / 20.0;
+            let pose = path.pose_at(distance).unwrap();
+
+            let backwards = reversed.pose_at(length - distance).unwrap();
+            assert!(
+                backwards.approx_eq(Pose::new(pose.point, (pose.angle + Angle::pi()).positive()))
+            );
+
+            let moved = path.transform(&transform).pose_at(distance).unwrap();
+            assert!(moved.approx_eq(Pose::new(
+                transform.transform_point(pose.point),
+                (pose.angle - Angle::radians(0.7)).positive()
+            )));
+
+            let mirror = mirrored.pose_at(distance).unwrap();
+            assert!(mirror.approx_eq(Pose::new(
+                Point::new(-pose.point.x, pose.point.y),
+                (-pose.angle).positive()
+            )));
+        }
+        assert!(mirrored
+            .curvature_at(0.0)
+            .unwrap()
+            .approx_eq(&-path.curvature_at(0.0).unwrap()));
+    }
+
+    #[test]
+    fn test_empty_path() {
+        let path = SegmentPath::<f64>::new(Vec::new());
+
+        assert!(path.is_empty());
+        assert!(path.get_length().approx_eq(&0.0));
+        assert!(path.pose_at(1.0).is_none());
+        assert!(path.sample(0.1).is_empty());
+    }
+}
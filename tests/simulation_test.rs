@@ -0,0 +1,172 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::routes;
+    use dubins_path::controller::*;
+    use dubins_path::simulation::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn start() -> Pose<f64> {
+        Pose::new(Point::new(0.0, 0.0), Angle::zero())
+    }
+
+    #[test]
+    fn test_drive_circle() {
+        let mut simulator = Simulator::new(Model::Unicycle, start());
+
+        // a quarter circle to the left with radius 2
+        simulator.step(1.0, 0.5, std::f64::consts::PI);
+        assert!(simulator.pose.approx_eq(Pose::new(
+            Point::new(-2.0, 2.0),
+            Angle::radians(3.0 * std::f64::consts::FRAC_PI_2)
+        )));
+        assert!(simulator.time.approx_eq(&std::f64::consts::PI));
+
+        // and straight on
+        simulator.step(2.0, 0.0, 1.0);
+        assert!(simulator.pose.point.approx_eq(&Point::new(-4.0, 2.0)));
+    }
+
+    #[test]
+    fn test_bicycle_limits_curvature() {
+        let model = Model::Bicycle {
+            wheelbase: 2.0,
+            max_steering_angle: Angle::radians(0.5_f64.atan()),
+        };
+
+        assert!(model.curvature(0.1).approx_eq(&0.1));
+        assert!(model.curvature(1.0).approx_eq(&0.25));
+        assert!(model.curvature(-1.0).approx_eq(&-0.25));
+    }
+
+    #[test]
+    fn test_routes_reach_end_pose() {
+        for (end, path) in routes() {
+            let execution = execute(
+                &path,
+                Model::Unicycle,
+                1.0,
+                0.1,
+                &start(),
+                end.point,
+                end.angle,
+            )
+            .unwrap();
+
+            assert!(execution.position_error < 1e-9);
+            assert!(execution.angle_error.radians.abs() < 1e-9);
+            assert!(execution.time.approx_eq(&path.get_length()));
+        }
+    }
+
+    #[test]
+    fn test_corrupted_route_is_noticed() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+        let mut route = RouteCSC::rsr(1.0, end_point, end_angle).unwrap();
+        route.start.angle += Angle::radians(0.7);
+
+        let execution = execute(
+            &Path::CSC(route),
+            Model::Unicycle,
+            1.0,
+            0.1,
+            &start(),
+            end_point,
+            end_angle,
+        )
+        .unwrap();
+        assert!(execution.position_error > 0.1);
+        assert!(execution.angle_error.radians.abs() > 0.1);
+    }
+
+    #[test]
+    fn test_placed_path() {
+        let end = Pose::new(Point::new(-5.0, 8.0), Angle::radians(2.0));
+        let origin = Pose::new(Point::new(10.0, -3.0), Angle::radians(1.0));
+        let path = get_shortest(2.0, end.point, end.angle).transform(
+            &Transform::rotation(-origin.angle).then_translate(origin.point.to_vector()),
+        );
+        let placed_end = end.from_relative(&origin);
+
+        let execution = execute(
+            &path,
+            Model::Unicycle,
+            1.0,
+            0.1,
+            &origin,
+            placed_end.point,
+            placed_end.angle,
+        )
+        .unwrap();
+        assert!(execution.position_error < 1e-9);
+        assert!(execution.angle_error.radians.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bicycle_misses_tight_path() {
+        let end_point = Point::new(3.0, -2.0);
+        let end_angle = Angle::radians(2.0);
+        let path = get_shortest(1.0, end_point, end_angle);
+
+        let fits = Model::Bicycle {
+            wheelbase: 1.0,
+            max_steering_angle: Angle::radians(0.8),
+        };
+        let too_wide = Model::Bicycle {
+            wheelbase: 1.0,
+            max_steering_angle: Angle::radians(0.5),
+        };
+
+        let execution = execute(&path, fits, 1.0, 0.1, &start(), end_point, end_angle).unwrap();
+        assert!(execution.position_error < 1e-9);
+        let execution = execute(&path, too_wide, 1.0, 0.1, &start(), end_point, end_angle).unwrap();
+        assert!(execution.position_error > 0.1);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let end = Point::new(0.0, 10.0);
+
+        assert!(execute(
+            &path,
+            Model::Unicycle,
+            0.0,
+            0.1,
+            &start(),
+            end,
+            Angle::zero()
+        )
+        .is_err());
+        assert!(execute(
+            &path,
+            Model::Unicycle,
+            1.0,
+            0.0,
+            &start(),
+            end,
+            Angle::zero()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_closed_loop_with_controller() {
+        let end_point = Point::new(-5.0, 8.0);
+        let end_angle = Angle::radians(2.0);
+        let path = get_shortest(2.0, end_point, end_angle);
+        let controller = PurePursuit::new(0.5);
+
+        let offset = Pose::new(Point::new(0.2, 0.0), Angle::zero());
+        let mut simulator = Simulator::new(Model::Unicycle, offset);
+        while simulator.time < path.get_length() {
+            let curvature = controller.curvature(&simulator.pose, &path);
+            simulator.step(1.0, curvature, 0.01);
+        }
+
+        assert!((simulator.pose.point - end_point).length() < 0.3);
+    }
+}
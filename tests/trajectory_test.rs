@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use dubins_path::trajectory::*;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    fn limits() -> Limits<f64> {
+        Limits {
+            max_speed: 2.0,
+            max_acceleration: 1.0,
+            max_deceleration: 2.0,
+            max_lateral_acceleration: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_straight_trajectory() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let trajectory = Trajectory::new(path, limits()).unwrap();
+
+        // 2s accelerating, 3.5s cruising and 1s decelerating
+        assert!(trajectory.duration().approx_eq(&6.5));
+
+        let state = trajectory.state_at(1.0);
+        assert!(state.speed.approx_eq(&1.0));
+        assert!(state.distance.approx_eq(&0.5));
+        assert!(state.pose.point.approx_eq(&Point::new(0.0, 0.5)));
+        assert!(state.curvature.approx_eq(&0.0));
+
+        let state = trajectory.state_at(4.0);
+        assert!(state.speed.approx_eq(&2.0));
+        assert!(state.distance.approx_eq(&6.0));
+
+        let end = trajectory.state_at(10.0);
+        assert!(end.speed.approx_eq(&0.0));
+        assert!(end
+            .pose
+            .approx_eq(Pose::new(Point::new(0.0, 10.0), Angle::zero())));
+    }
+
+    #[test]
+    fn test_limits_are_kept() {
+        let path = get_shortest(1.0, Point::new(3.0, -2.0), Angle::radians(2.0));
+        let trajectory = Trajectory::new(path, limits()).unwrap();
+
+        let step = 0.001;
+        let mut previous = trajectory.state_at(0.0);
+        assert!(previous.speed.approx_eq(&0.0));
+
+        for index in 1..=((trajectory.duration() / step) as usize) {
+            let state = trajectory.state_at(index as f64 * step);
+
+            assert!(state.speed <= 2.0 + 1e-9);
+            assert!(state.speed * state.speed * state.curvature.abs() <= 1.0 + 1e-9);
+            assert!(state.speed - previous.speed <= 1.0 * step + 1e-9);
+            assert!(previous.speed - state.speed <= 2.0 * step + 1e-9);
+            // the distance is the integral of the speed (exact unless a phase changes)
+            let driven = (state.speed + previous.speed) / 2.0 * step;
+            assert!((state.distance - previous.distance - driven).abs() < 1e-6);
+
+            previous = state;
+        }
+
+        let end = trajectory.state_at(trajectory.duration());
+        assert!(end.distance.approx_eq(&path.get_length()));
+        assert!(end.speed.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_speed_capped_on_circles() {
+        let path =
+            Path::CSC(RouteCSC::lsl(4.0, Point::new(-20.0, 30.0), Angle::radians(4.0)).unwrap());
+        let limits = Limits {
+            max_speed: 10.0,
+            max_acceleration: 100.0,
+            max_deceleration: 100.0,
+            max_lateral_acceleration: 1.0,
+        };
+        let trajectory = Trajectory::new(path, limits).unwrap();
+
+        let arc = path.to_normalized().lengths[0] * 4.0;
+        let state = trajectory.state_at(0.5);
+        assert!(state.distance < arc);
+        assert!(state.speed.approx_eq(&2.0));
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        let path = get_shortest(1.0, Point::new(0.0, 10.0), Angle::zero());
+        let limits = Limits {
+            max_acceleration: 0.0,
+            ..limits()
+        };
+
+        assert!(Trajectory::new(path, limits).is_err());
+    }
+}
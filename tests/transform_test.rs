@@ -0,0 +1,182 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::paths;
+    use dubins_path::*;
+    use euclid::approxeq::ApproxEq;
+
+    /// compare the poses along both paths, the other path's poses mapped by the function
+    fn assert_poses<F>(path: &Path<f64>, other: &Path<f64>, mapped: F)
+    where
+        F: Fn(f64) -> Pose<f64>,
+    {
+        assert!(path.get_length().approx_eq(&other.get_length()));
+        for index in 0..=20 {
+            let distance = path.get_length() * index as f64 / 20.0;
+            let pose = path.pose_at(distance);
+            let expected = mapped(distance);
+
+            assert!(
+                (pose.point - expected.point).length() < 1e-9,
+                "{:?} at {}: {:?} != {:?}",
+                path,
+                distance,
+                pose,
+                expected
+            );
+            assert!((pose.angle - expected.angle).signed().radians.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rigid_transform() {
+        let angle = Angle::radians(0.7);
+        let transform = Transform::rotation(angle).then_translate(Vector::new(4.0, -1.0));
+
+        for path in paths() {
+            let moved = path.transform(&transform);
+            assert_eq!(moved.path_type(), path.path_type());
+
+            // the heading is clockwise, so a counter clockwise rotation decreases it
+            assert_poses(&moved, &path, |distance| {
+                let pose = path.pose_at(distance);
+                Pose::new(
+                    transform.transform_point(pose.point),
+                    (pose.angle - angle).positive(),
+                )
+            });
+        }
+    }
+
+    #[test]
+    fn test_mirror_about_start() {
+        let axis = Pose::new(Point::new(0.0, 0.0), Angle::zero());
+
+        for path in paths() {
+            let mirrored = path.mirror_about_axis(&axis);
+            let expected = match path.path_type() {
+                PathType::RSR => PathType::LSL,
+                PathType::RSL => PathType::LSR,
+                PathType::LSR => PathType::RSL,
+                PathType::LSL => PathType::RSR,
+                PathType::RLR => PathType::LRL,
+                PathType::LRL => PathType::RLR,
+            };
+            assert_eq!(mirrored.path_type(), expected);
+
+            assert_poses(&mirrored, &path, |distance| {
+                let pose = path.pose_at(distance);
+                Pose::new(
+                    Point::new(-pose.point.x, pose.point.y),
+                    (-pose.angle).positive(),
+                )
+            });
+        }
+    }
+
+    #[test]
+    fn test_mirror_about_axis() {
+        // mirroring about the line y = x swaps the coordinates
+        let axis = Pose::new(Point::new(1.0, 1.0), Angle::frac_pi_4());
+        let straight = StraightPath {
+            origin: Point::new(2.0, 0.0),
+            vector: Vector::new(0.0, 3.0),
+        };
+        let circle = CirclePath {
+            center: Point::new(-1.0, 5.0),
+            radius: 2.0,
+            angle: Angle::radians(1.0),
+        };
+
+        assert!(straight.mirror_about_axis(&axis).approx_eq(StraightPath {
+            origin: Point::new(0.0, 2.0),
+            vector: Vector::new(3.0, 0.0),
+        }));
+        assert!(circle.mirror_about_axis(&axis).approx_eq(CirclePath {
+            center: Point::new(5.0, -1.0),
+            ..circle
+        }));
+    }
+
+    #[test]
+    fn test_reversed() {
+        for path in paths() {
+            let reversed = path.reversed();
+            let length = path.get_length();
+            let expected = match path.path_type() {
+                PathType::RSR => PathType::LSL,
+                PathType::LSL => PathType::RSR,
+                PathType::RLR => PathType::LRL,
+                PathType::LRL => PathType::RLR,
+                path_type => path_type,
+            };
+            assert_eq!(reversed.path_type(), expected);
+
+            // the reversed path passes the same poses backwards facing the other way
+            assert_poses(&reversed, &path, |distance| {
+                let pose = path.pose_at(length - distance);
+                Pose::new(pose.point, (pose.angle + Angle::pi()).positive())
+            });
+        }
+    }
+
+    #[test]
+    fn test_reversed_straight_and_circle() {
+        let straight = StraightPath {
+            origin: Point::new(1.0, 2.0),
+            vector: Vector::new(3.0, -4.0),
+        };
+
+        assert!(straight.reversed().approx_eq(StraightPath {
+            origin: Point::new(4.0, -2.0),
+            vector: Vector::new(-3.0, 4.0),
+        }));
+        assert!(straight.reversed().reversed().approx_eq(straight));
+
+        // a circle has no direction to reverse
+        let circle = CirclePath {
+            center: Point::new(-1.0, 5.0),
+            radius: 2.0,
+            angle: Angle::radians(1.0),
+        };
+        assert!(circle.reversed().approx_eq(circle));
+    }
+
+    #[test]
+    fn test_zero_length_tangent() {
+        // half circles without a tangent and an end circle
+        let left = get_shortest(1.0, Point::new(-2.0, 0.0), Angle::pi());
+        let right = get_shortest(1.0, Point::new(2.0, 0.0), Angle::pi());
+        assert_eq!(left.path_type(), PathType::LSL);
+        assert_eq!(right.path_type(), PathType::RSR);
+
+        let transform = Transform::translation(10.0, 0.0);
+        for path in [left, right].iter() {
+            let moved = path.transform(&transform);
+            assert_eq!(moved.path_type(), path.path_type());
+            assert_poses(&moved, path, |distance| {
+                let pose = path.pose_at(distance);
+                Pose::new(transform.transform_point(pose.point), pose.angle)
+            });
+
+            let reversed = moved.reversed();
+            let length = moved.get_length();
+            assert!(reversed
+                .pose_at(0.0)
+                .approx_eq(Pose::new(moved.pose_at(length).point, Angle::zero())));
+            assert_poses(&reversed, &moved, |distance| {
+                let pose = moved.pose_at(length - distance);
+                Pose::new(pose.point, (pose.angle + Angle::pi()).positive())
+            });
+        }
+
+        let mirrored = left
+            .transform(&transform)
+            .mirror_about_axis(&Pose::new(Point::new(10.0, 0.0), Angle::zero()));
+        assert_eq!(mirrored.path_type(), PathType::RSR);
+        assert!(mirrored
+            .pose_at(mirrored.get_length())
+            .approx_eq(Pose::new(Point::new(12.0, 0.0), Angle::pi())));
+    }
+}
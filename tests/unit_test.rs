@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::redundant_field_names)]
 mod tests {
     use dubins_path::*;
 
@@ -60,6 +61,8 @@ mod tests {
                     radius: radius,
                     angle: Angle::zero(),
                 },
+                start_direction: Direction::Right,
+                end_direction: Direction::Right,
             };
 
             let result_rsr = RouteCSC::rsr(radius, end_point, end_angle).unwrap();
@@ -92,6 +95,8 @@ mod tests {
                     radius: radius,
                     angle: Angle::zero(),
                 },
+                start_direction: Direction::Right,
+                end_direction: Direction::Left,
             };
 
             let result_rsl = RouteCSC::rsl(radius, end_point, end_angle).unwrap();
@@ -124,6 +129,8 @@ mod tests {
                     radius: radius,
                     angle: Angle::zero(),
                 },
+                start_direction: Direction::Left,
+                end_direction: Direction::Left,
             };
 
             let result_lsl = RouteCSC::lsl(radius, end_point, end_angle).unwrap();
@@ -156,6 +163,8 @@ mod tests {
                     radius: radius,
                     angle: Angle::zero(),
                 },
+                start_direction: Direction::Left,
+                end_direction: Direction::Right,
             };
 
             let result_lsr = RouteCSC::lsr(radius, end_point, end_angle).unwrap();
@@ -189,6 +198,7 @@ mod tests {
                     radius: radius,
                     angle: Angle::pi(),
                 },
+                direction: Direction::Right,
             };
 
             let result_rlr = RouteCCC::rlr(radius, end_point, end_angle).unwrap();
@@ -222,6 +232,7 @@ mod tests {
                     radius: radius,
                     angle: Angle::pi(),
                 },
+                direction: Direction::Left,
             };
 
             let result_lrl = RouteCCC::lrl(radius, end_point, end_angle).unwrap();
@@ -232,4 +243,431 @@ mod tests {
             assert!(result_lrl.end.approx_eq(expected_result_lrl.end));
         }
     }
+
+    #[test]
+    fn test_end_circle_lsl() {
+        // a quarter turn left, two straight to the left and a half turn left
+        let radius = 1.0;
+        let result_lsl = RouteCSC::lsl(radius, Point::new(-3.0, -1.0), Angle::frac_pi_2()).unwrap();
+
+        assert!(result_lsl.start.approx_eq(CirclePath {
+            center: Point::new(-1.0, 0.0),
+            radius,
+            angle: Angle::frac_pi_2(),
+        }));
+        assert!(result_lsl.tangent.approx_eq(StraightPath {
+            origin: Point::new(-1.0, 1.0),
+            vector: Vector::new(-2.0, 0.0),
+        }));
+        // the end circle used to turn by the difference of the end and start angle
+        assert!(result_lsl.end.approx_eq(CirclePath {
+            center: Point::new(-3.0, 0.0),
+            radius,
+            angle: Angle::pi(),
+        }));
+    }
+
+    #[test]
+    fn test_end_circle_lsr() {
+        // a quarter turn left, two straight to the left and a quarter turn right
+        let radius = 1.0;
+        let result_lsr = RouteCSC::lsr(radius, Point::new(-4.0, 2.0), Angle::zero()).unwrap();
+
+        assert!(result_lsr.start.approx_eq(CirclePath {
+            center: Point::new(-1.0, 0.0),
+            radius,
+            angle: Angle::frac_pi_2(),
+        }));
+        assert!(result_lsr.tangent.approx_eq(StraightPath {
+            origin: Point::new(-1.0, 1.0),
+            vector: Vector::new(-2.0, 0.0),
+        }));
+        // the end circle used to turn in the wrong direction
+        assert!(result_lsr.end.approx_eq(CirclePath {
+            center: Point::new(-3.0, 2.0),
+            radius,
+            angle: Angle::frac_pi_2(),
+        }));
+    }
+
+    #[test]
+    fn test_middle_circle_rlr() {
+        // the outer circles are two radii apart, the middle circle lies below them
+        let radius = 1.0;
+        let result_rlr = RouteCCC::rlr(radius, Point::new(2.0, 0.0), Angle::zero()).unwrap();
+        let third: f64 = std::f64::consts::PI / 3.0;
+
+        // the middle circle used to lie above them with a middle arc shorter than π
+        // and the end circle turned the other way
+        assert!(result_rlr.start.approx_eq(CirclePath {
+            center: Point::new(1.0, 0.0),
+            radius,
+            angle: Angle::radians(4.0 * third),
+        }));
+        assert!(result_rlr.middle.approx_eq(CirclePath {
+            center: Point::new(2.0, -(3.0f64).sqrt()),
+            radius,
+            angle: Angle::radians(5.0 * third),
+        }));
+        assert!(result_rlr.end.approx_eq(CirclePath {
+            center: Point::new(3.0, 0.0),
+            radius,
+            angle: Angle::radians(third),
+        }));
+    }
+
+    #[test]
+    fn test_middle_circle_lrl() {
+        // mirrored rlr
+        let radius = 1.0;
+        let result_lrl = RouteCCC::lrl(radius, Point::new(-2.0, 0.0), Angle::zero()).unwrap();
+        let third: f64 = std::f64::consts::PI / 3.0;
+
+        assert!(result_lrl.start.approx_eq(CirclePath {
+            center: Point::new(-1.0, 0.0),
+            radius,
+            angle: Angle::radians(4.0 * third),
+        }));
+        assert!(result_lrl.middle.approx_eq(CirclePath {
+            center: Point::new(-2.0, -(3.0f64).sqrt()),
+            radius,
+            angle: Angle::radians(5.0 * third),
+        }));
+        assert!(result_lrl.end.approx_eq(CirclePath {
+            center: Point::new(-3.0, 0.0),
+            radius,
+            angle: Angle::radians(third),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod shortest_length_tests {
+    use dubins_path::*;
+
+    #[test]
+    fn test_shortest_length_matches_get_shortest() {
+        let radius = 1.3;
+
+        for x in -12..=12 {
+            for y in -12..=12 {
+                for angle in 0..16 {
+                    let end_point = Point::new(x as f64 * 0.37, y as f64 * 0.41);
+                    let end_angle = Angle::radians(angle as f64 * 0.4);
+
+                    let expected = get_shortest(radius, end_point, end_angle).get_length();
+                    let length = shortest_length(radius, end_point, end_angle);
+
+                    assert!(
+                        (expected - length).abs() < 1e-9,
+                        "{:?} {:?}: {} != {}",
+                        end_point,
+                        end_angle,
+                        length,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shortest_length_straight() {
+        let length = shortest_length(0.5, Point::new(0.0, 10.0), Angle::zero());
+        assert!((length - 10.0).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod degenerate_tests {
+    use dubins_path::*;
+
+    /// follow the path to its end and return the end point and the end angle
+    fn end_pose(path: Path<f64>) -> (Point<f64>, Angle<f64>) {
+        let path_type = path.path_type();
+        let turn = |point: Point<f64>, circle: CirclePath<f64>, direction: Direction| {
+            let angle = match direction {
+                Direction::Left => circle.angle.radians,
+                Direction::Right => -circle.angle.radians,
+            };
+            let (sin, cos) = angle.sin_cos();
+            let v = point - circle.center;
+            circle.center + Vector::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+        };
+
+        let (point, circle) = match path {
+            Path::CSC(route) => (
+                turn(
+                    route.tangent.origin + route.tangent.vector,
+                    route.end,
+                    path_type.end_direction(),
+                ),
+                route.end,
+            ),
+            Path::CCC(route) => (
+                turn(
+                    route.middle.center.lerp(route.end.center, 0.5),
+                    route.end,
+                    path_type.end_direction(),
+                ),
+                route.end,
+            ),
+        };
+
+        // the heading is perpendicular to the radius, clockwise from the y axis
+        let v = point - circle.center;
+        let heading = match path_type.end_direction() {
+            Direction::Left => Vector::new(-v.y, v.x),
+            Direction::Right => Vector::new(v.y, -v.x),
+        };
+        (point, Angle::radians(heading.x.atan2(heading.y)))
+    }
+
+    fn assert_reaches(path: Path<f64>, end_point: Point<f64>, end_angle: Angle<f64>) {
+        let (point, angle) = end_pose(path);
+        assert!(
+            (point - end_point).length() < 1e-6,
+            "{:?} ends at {:?} instead of {:?}",
+            path,
+            point,
+            end_point
+        );
+        assert!(
+            (angle - end_angle).signed().radians.abs() < 1e-6,
+            "{:?} ends with {:?} instead of {:?}",
+            path,
+            angle,
+            end_angle
+        );
+    }
+
+    #[test]
+    fn test_end_at_start() {
+        let radius = 0.5;
+        let end_point = Point::new(0.0, 0.0);
+        let end_angle = Angle::zero();
+
+        for route in [
+            RouteCSC::rsr(radius, end_point, end_angle).unwrap(),
+            RouteCSC::lsl(radius, end_point, end_angle).unwrap(),
+            RouteCSC::rsl(radius, end_point, end_angle).unwrap(),
+            RouteCSC::lsr(radius, end_point, end_angle).unwrap(),
+        ]
+        .iter()
+        {
+            assert!(route.get_length().abs() < 1e-9, "{:?}", route);
+        }
+        for route in [
+            RouteCCC::rlr(radius, end_point, end_angle).unwrap(),
+            RouteCCC::lrl(radius, end_point, end_angle).unwrap(),
+        ]
+        .iter()
+        {
+            assert_reaches(Path::CCC(*route), end_point, end_angle);
+        }
+
+        let path = get_shortest(radius, end_point, end_angle);
+        assert!(path.get_length().abs() < 1e-9);
+        assert!(shortest_length(radius, end_point, end_angle).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_coincident_centers_rsr() {
+        let radius = 0.5;
+        let end_point = Point::new(1.0, 0.0);
+        let end_angle = Angle::pi();
+
+        let expected_result_rsr = RouteCSC {
+            start: CirclePath {
+                center: Point::new(0.5, 0.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(1.0, 0.0),
+                vector: Vector::zero(),
+            },
+            end: CirclePath {
+                center: Point::new(0.5, 0.0),
+                radius,
+                angle: Angle::zero(),
+            },
+            start_direction: Direction::Right,
+            end_direction: Direction::Right,
+        };
+
+        let result_rsr = RouteCSC::rsr(radius, end_point, end_angle).unwrap();
+
+        assert!(result_rsr.start.approx_eq(expected_result_rsr.start));
+        assert!(result_rsr.tangent.approx_eq(expected_result_rsr.tangent));
+        assert!(result_rsr.end.approx_eq(expected_result_rsr.end));
+    }
+
+    #[test]
+    fn test_coincident_centers_lsl() {
+        let radius = 0.5;
+        let end_point = Point::new(-1.0, 0.0);
+        let end_angle = Angle::pi();
+
+        let expected_result_lsl = RouteCSC {
+            start: CirclePath {
+                center: Point::new(-0.5, 0.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(-1.0, 0.0),
+                vector: Vector::zero(),
+            },
+            end: CirclePath {
+                center: Point::new(-0.5, 0.0),
+                radius,
+                angle: Angle::zero(),
+            },
+            start_direction: Direction::Left,
+            end_direction: Direction::Left,
+        };
+
+        let result_lsl = RouteCSC::lsl(radius, end_point, end_angle).unwrap();
+
+        assert!(result_lsl.start.approx_eq(expected_result_lsl.start));
+        assert!(result_lsl.tangent.approx_eq(expected_result_lsl.tangent));
+        assert!(result_lsl.end.approx_eq(expected_result_lsl.end));
+    }
+
+    #[test]
+    fn test_touching_circles_rsl() {
+        let radius = 0.5;
+        let end_point = Point::new(1.0, 0.0);
+        let end_angle = Angle::pi();
+
+        let expected_result_rsl = RouteCSC {
+            start: CirclePath {
+                center: Point::new(0.5, 0.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(1.0, 0.0),
+                vector: Vector::zero(),
+            },
+            end: CirclePath {
+                center: Point::new(1.5, 0.0),
+                radius,
+                angle: Angle::zero(),
+            },
+            start_direction: Direction::Right,
+            end_direction: Direction::Left,
+        };
+
+        let result_rsl = RouteCSC::rsl(radius, end_point, end_angle).unwrap();
+
+        assert!(result_rsl.start.approx_eq(expected_result_rsl.start));
+        assert!(result_rsl.tangent.approx_eq(expected_result_rsl.tangent));
+        assert!(result_rsl.end.approx_eq(expected_result_rsl.end));
+    }
+
+    #[test]
+    fn test_touching_circles_lsr() {
+        let radius = 0.5;
+        let end_point = Point::new(-1.0, 0.0);
+        let end_angle = Angle::pi();
+
+        let expected_result_lsr = RouteCSC {
+            start: CirclePath {
+                center: Point::new(-0.5, 0.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(-1.0, 0.0),
+                vector: Vector::zero(),
+            },
+            end: CirclePath {
+                center: Point::new(-1.5, 0.0),
+                radius,
+                angle: Angle::zero(),
+            },
+            start_direction: Direction::Left,
+            end_direction: Direction::Right,
+        };
+
+        let result_lsr = RouteCSC::lsr(radius, end_point, end_angle).unwrap();
+
+        assert!(result_lsr.start.approx_eq(expected_result_lsr.start));
+        assert!(result_lsr.tangent.approx_eq(expected_result_lsr.tangent));
+        assert!(result_lsr.end.approx_eq(expected_result_lsr.end));
+    }
+
+    #[test]
+    fn test_vertical_alignment_rsr() {
+        let radius = 0.5;
+        let end_point = Point::new(0.0, -10.0);
+        let end_angle = Angle::zero();
+
+        let expected_result_rsr = RouteCSC {
+            start: CirclePath {
+                center: Point::new(0.5, 0.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            tangent: StraightPath {
+                origin: Point::new(1.0, 0.0),
+                vector: Vector::new(0.0, -10.0),
+            },
+            end: CirclePath {
+                center: Point::new(0.5, -10.0),
+                radius,
+                angle: Angle::pi(),
+            },
+            start_direction: Direction::Right,
+            end_direction: Direction::Right,
+        };
+
+        let result_rsr = RouteCSC::rsr(radius, end_point, end_angle).unwrap();
+
+        assert!(result_rsr.start.approx_eq(expected_result_rsr.start));
+        assert!(result_rsr.tangent.approx_eq(expected_result_rsr.tangent));
+        assert!(result_rsr.end.approx_eq(expected_result_rsr.end));
+    }
+
+    #[test]
+    fn test_all_routes_reach_the_end() {
+        let radius = 0.5;
+
+        // includes the start pose, points on the start circles and
+        // points where the circles touch each other
+        for x in -8..=8 {
+            for y in -8..=8 {
+                for angle in 0..8 {
+                    let end_point = Point::new(x as f64 * 0.25, y as f64 * 0.25);
+                    let end_angle = Angle::radians(angle as f64 * std::f64::consts::FRAC_PI_4);
+
+                    for route in [
+                        RouteCSC::rsr(radius, end_point, end_angle),
+                        RouteCSC::lsl(radius, end_point, end_angle),
+                        RouteCSC::rsl(radius, end_point, end_angle),
+                        RouteCSC::lsr(radius, end_point, end_angle),
+                    ]
+                    .iter()
+                    .flatten()
+                    {
+                        assert_reaches(Path::CSC(*route), end_point, end_angle);
+                    }
+                    for route in [
+                        RouteCCC::rlr(radius, end_point, end_angle),
+                        RouteCCC::lrl(radius, end_point, end_angle),
+                    ]
+                    .iter()
+                    .flatten()
+                    {
+                        assert_reaches(Path::CCC(*route), end_point, end_angle);
+                    }
+
+                    let path = get_shortest(radius, end_point, end_angle);
+                    assert_reaches(path, end_point, end_angle);
+                }
+            }
+        }
+    }
 }